@@ -0,0 +1,48 @@
+//! Benchmarks of [`OracleStorage`] with `u64` moments, as used by the runtime
+//!
+//! Run with `cargo bench -p simple-oracle-data`
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use simple_oracle_data::OracleStorage;
+use sp_core::ConstU64;
+
+const ENTRIES: u64 = 4096;
+
+type Storage = OracleStorage<u64, ConstU64<ENTRIES>>;
+
+/// Storage with an entry saved at each moment of `0..ENTRIES`
+fn full_storage() -> Storage {
+	let mut storage = Storage::default();
+	for moment in 0..ENTRIES {
+		storage.append(moment, moment.to_be_bytes().to_vec()).unwrap();
+	}
+	storage
+}
+
+fn bench_oracle_storage(c: &mut Criterion) {
+	let storage = full_storage();
+	// Half of the entries are outdated
+	let now = ENTRIES + ENTRIES / 2;
+
+	c.bench_function("append", |b| {
+		b.iter_batched(
+			|| storage.clone(),
+			|mut storage| storage.append(black_box(now), b"data".to_vec()).unwrap(),
+			BatchSize::LargeInput,
+		)
+	});
+	c.bench_function("iter_data", |b| b.iter(|| storage.iter_data(black_box(now)).count()));
+	c.bench_function("value_at_or_before", |b| {
+		b.iter(|| storage.value_at_or_before(black_box(now), black_box(now - 1)).is_some())
+	});
+	c.bench_function("clean_outdated_data", |b| {
+		b.iter_batched(
+			|| storage.clone(),
+			|mut storage| storage.clean_outdated_data(black_box(now)).unwrap(),
+			BatchSize::LargeInput,
+		)
+	});
+}
+
+criterion_group!(benches, bench_oracle_storage);
+criterion_main!(benches);
@@ -0,0 +1,992 @@
+//! Storage structures for oracle data
+//!
+//! Time-windowed storage of oracle data, independent of FRAME,
+//! so it can be reused by clients, tests & other pallets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, DecodeAll, Encode, MaxEncodedLen};
+use sp_arithmetic::traits::AtLeast32BitUnsigned;
+use sp_core::{Get, RuntimeDebug};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+pub type Data = Vec<u8>;
+
+/// Numeric value of the payload
+///
+/// Numeric payloads are SCALE encoded `u128`
+pub fn decode_value(data: &[u8]) -> Option<u128> {
+	u128::decode_all(&mut &data[..]).ok()
+}
+
+/// Linear interpolation between `from` & `to` at `elapsed / span`
+///
+/// The difference is split into whole & remainder parts of `span`,
+/// so the multiplication doesn't overflow for 64-bit spans.
+fn interpolate(from: u128, to: u128, elapsed: u128, span: u128) -> u128 {
+	let scale = |diff: u128| {
+		(diff / span)
+			.saturating_mul(elapsed)
+			.saturating_add((diff % span).saturating_mul(elapsed) / span)
+	};
+
+	if to >= from {
+		from.saturating_add(scale(to - from))
+	} else {
+		from.saturating_sub(scale(from - to))
+	}
+}
+
+#[derive(RuntimeDebug, Encode, Decode, Default, Clone, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OracleData<MOMENT> {
+	data: Data,
+	saved_at: MOMENT,
+	/// Uncertainty of a numeric payload, in the same units
+	confidence: Option<u128>,
+}
+
+impl<MOMENT: Clone> OracleData<MOMENT> {
+	/// Entry of `data` saved at `saved_at`, e.g. to be stored outside of [`OracleStorage`]
+	pub fn new(data: Data, saved_at: MOMENT, confidence: Option<u128>) -> Self {
+		Self { data, saved_at, confidence }
+	}
+
+	pub fn data(&self) -> &[u8] {
+		&self.data
+	}
+
+	pub fn saved_at(&self) -> MOMENT {
+		self.saved_at.clone()
+	}
+
+	pub fn confidence(&self) -> Option<u128> {
+		self.confidence
+	}
+
+	pub fn into_data(self) -> Data {
+		self.data
+	}
+}
+
+impl<MOMENT: PartialOrd> PartialOrd for OracleData<MOMENT> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		self.saved_at.partial_cmp(&other.saved_at)
+	}
+}
+
+/// Oracle data in chronological order
+///
+/// Data is alive for `LIFETIME` since it's saved, the lifetime is a part of the
+/// type, so all reads & cleanups of the same storage agree on it.
+#[derive(Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(LIFETIME))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OracleStorage<MOMENT, LIFETIME>(
+	Vec<OracleData<MOMENT>>,
+	#[codec(skip)]
+	#[cfg_attr(feature = "std", serde(skip))]
+	PhantomData<LIFETIME>,
+);
+
+// Manual impls, so `LIFETIME` isn't required to implement the traits
+
+impl<MOMENT, LIFETIME> Default for OracleStorage<MOMENT, LIFETIME> {
+	fn default() -> Self {
+		Self(Vec::new(), PhantomData)
+	}
+}
+
+impl<MOMENT: Clone, LIFETIME> Clone for OracleStorage<MOMENT, LIFETIME> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone(), PhantomData)
+	}
+}
+
+impl<MOMENT: PartialEq, LIFETIME> PartialEq for OracleStorage<MOMENT, LIFETIME> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl<MOMENT: core::fmt::Debug, LIFETIME> core::fmt::Debug for OracleStorage<MOMENT, LIFETIME> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.debug_tuple("OracleStorage").field(&self.0).finish()
+	}
+}
+
+/// Entries are kept in iteration order, see [`OracleStorage::is_sorted`]
+impl<MOMENT, LIFETIME> FromIterator<OracleData<MOMENT>> for OracleStorage<MOMENT, LIFETIME> {
+	fn from_iter<I: IntoIterator<Item = OracleData<MOMENT>>>(iter: I) -> Self {
+		Self(iter.into_iter().collect(), PhantomData)
+	}
+}
+
+/// Position to continue a paginated read from
+///
+/// Several entries can be saved at the same moment,
+/// so the cursor points to the `skip`-th of them.
+#[derive(RuntimeDebug, Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Cursor<MOMENT> {
+	pub saved_at: MOMENT,
+	pub skip: u32,
+}
+
+/// Bounded chunk of data in chronological order
+///
+/// `next` is set if there is more data left to read. `ITEM` is the payload
+/// unless it's decoded, e.g. into structured entries.
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Page<MOMENT, ITEM = Data> {
+	pub data: Vec<ITEM>,
+	pub next: Option<Cursor<MOMENT>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+	/// An attempt was made to insert outdated data
+	///
+	/// For simplicity, you can only add data to a storage
+	/// if there is no newer data in it.
+	AttemptToInsertHistoricalData,
+}
+
+impl<MOMENT, LIFETIME> OracleStorage<MOMENT, LIFETIME> {
+	/// Amount of stored entries, including outdated ones
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Stored entries in chronological order, including outdated ones
+	pub fn iter(&self) -> impl Iterator<Item = &OracleData<MOMENT>> {
+		self.0.iter()
+	}
+
+	/// The oldest stored entry, even if it's outdated
+	pub fn oldest(&self) -> Option<&OracleData<MOMENT>> {
+		self.0.first()
+	}
+
+	/// The newest stored entry, even if it's outdated
+	pub fn newest(&self) -> Option<&OracleData<MOMENT>> {
+		self.0.last()
+	}
+
+	/// Total payload bytes of stored entries, including outdated ones
+	pub fn total_bytes(&self) -> usize {
+		self.0.iter().map(|oracle_data| oracle_data.data.len()).sum()
+	}
+
+	/// Stored entries split into runs of entries saved at the same moment, with the moment
+	///
+	/// Runs of sorted storage are saved at distinct moments, so storages keyed
+	/// by moments can store each run under its own key.
+	pub fn moments(&self) -> impl Iterator<Item = (MOMENT, &[OracleData<MOMENT>])>
+	where
+		MOMENT: Clone + PartialEq,
+	{
+		let mut rest = self.0.as_slice();
+		core::iter::from_fn(move || {
+			let saved_at = rest.first()?.saved_at.clone();
+			let len =
+				rest.iter().take_while(|oracle_data| oracle_data.saved_at == saved_at).count();
+			let (run, tail) = rest.split_at(len);
+			rest = tail;
+			Some((saved_at, run))
+		})
+	}
+
+	/// Blank payload of the `index`-th entry, even if it's outdated
+	///
+	/// The entry keeps its place, so positions of other entries don't change.
+	/// Returns the removed payload.
+	pub fn redact(&mut self, index: usize) -> Option<Data> {
+		self.0.get_mut(index).map(|oracle_data| core::mem::take(&mut oracle_data.data))
+	}
+}
+
+impl<MOMENT: Ord, LIFETIME> OracleStorage<MOMENT, LIFETIME> {
+	/// Check if entries are in chronological order, as all reads & cleanups expect
+	pub fn is_sorted(&self) -> bool {
+		self.0.windows(2).all(|pair| pair[0].saved_at <= pair[1].saved_at)
+	}
+
+	/// Restore chronological order of entries
+	///
+	/// Entries saved at the same moment keep their order
+	pub fn sort(&mut self) {
+		self.0.sort_by(|left, right| left.saved_at.cmp(&right.saved_at));
+	}
+
+	/// Insert data saved at `saved_at` keeping chronological order
+	///
+	/// Unlike [`OracleStorage::append`] accepts historical data,
+	/// which is placed after entries saved at the same moment.
+	pub fn insert_sorted(&mut self, saved_at: MOMENT, data: Data) {
+		let index = self.0.partition_point(|oracle_data| oracle_data.saved_at <= saved_at);
+		self.0.insert(index, OracleData { data, saved_at, confidence: None });
+	}
+
+	/// Move all entries of `other` into this storage keeping chronological order
+	///
+	/// Entries of this storage go first among entries saved at the same moment.
+	pub fn merge(&mut self, mut other: Self) {
+		self.0.append(&mut other.0);
+		// Stable sort of two sorted runs is a linear merge
+		self.sort();
+	}
+
+	/// Split storage at `moment`
+	///
+	/// Returns entries saved at `moment` or later,
+	/// while older entries are left in this storage.
+	pub fn split_off(&mut self, moment: MOMENT) -> Self {
+		let point = self.0.partition_point(|oracle_data| oracle_data.saved_at < moment);
+		Self(self.0.split_off(point), PhantomData)
+	}
+}
+
+impl<MOMENT: AtLeast32BitUnsigned + Clone + MaxEncodedLen, LIFETIME: Get<MOMENT>>
+	OracleStorage<MOMENT, LIFETIME>
+{
+	/// Alive data in chronological order
+	///
+	/// Outdated entries are skipped with a binary search,
+	/// so it takes `O(log n)` to start the iteration.
+	pub fn iter_data(&self, now: MOMENT) -> impl Iterator<Item = &[u8]> {
+		self.alive(&now).iter().map(|oracle_data| oracle_data.data.as_slice())
+	}
+
+	/// Owned alive data in chronological order, see [`OracleStorage::iter_data`]
+	///
+	/// Consumes the storage, so payloads are moved out instead of copied
+	pub fn into_iter_data(self, now: MOMENT) -> impl Iterator<Item = Data> {
+		let point = self.outdated_point(&now, &LIFETIME::get());
+		self.0.into_iter().skip(point).map(|oracle_data| oracle_data.data)
+	}
+
+	/// Alive entries
+	///
+	/// Entries are sorted by `saved_at`, so the outdated ones
+	/// are found with a binary search.
+	fn alive(&self, now: &MOMENT) -> &[OracleData<MOMENT>] {
+		&self.0[self.outdated_point(now, &LIFETIME::get())..]
+	}
+
+	/// Amount of the oldest entries alive longer than `lifetime`
+	fn outdated_point(&self, now: &MOMENT, lifetime: &MOMENT) -> usize {
+		self.0
+			.partition_point(|data| Self::is_older_than(now, &data.saved_at, lifetime))
+	}
+
+	/// Whether data saved at `saved_at` is alive longer than `lifetime` at `now`
+	///
+	/// Data saved after `now`, e.g. if the clock went backwards, is never outdated
+	fn is_older_than(now: &MOMENT, saved_at: &MOMENT, lifetime: &MOMENT) -> bool {
+		now.checked_sub(saved_at).map_or(false, |age| &age >= lifetime)
+	}
+
+	/// Alive entry at `index`, counting from the oldest stored entry
+	pub fn alive_at(&self, now: MOMENT, index: usize) -> Option<&OracleData<MOMENT>> {
+		let alive = self.alive(&now);
+		alive.get(index.checked_sub(self.0.len() - alive.len())?)
+	}
+
+	/// Alive data saved exactly at `moment`
+	pub fn data_at(&self, now: MOMENT, moment: MOMENT) -> impl Iterator<Item = &[u8]> {
+		let alive = self.alive(&now);
+		let start = alive.partition_point(|oracle_data| oracle_data.saved_at < moment);
+		let end = alive.partition_point(|oracle_data| oracle_data.saved_at <= moment);
+
+		alive[start..end].iter().map(|oracle_data| oracle_data.data.as_slice())
+	}
+
+	/// Alive data saved in `[from, to)` in chronological order
+	pub fn range(&self, now: MOMENT, from: MOMENT, to: MOMENT) -> impl Iterator<Item = &[u8]> {
+		let alive = self.alive(&now);
+		let start = alive.partition_point(|oracle_data| oracle_data.saved_at < from);
+		let end = alive.partition_point(|oracle_data| oracle_data.saved_at < to).max(start);
+
+		alive[start..end].iter().map(|oracle_data| oracle_data.data.as_slice())
+	}
+
+	/// The newest alive data saved not after `moment`,
+	/// together with its age at `moment`
+	pub fn value_at_or_before(&self, now: MOMENT, moment: MOMENT) -> Option<(&[u8], MOMENT)> {
+		let alive = self.alive(&now);
+		let oracle_data =
+			alive[..alive.partition_point(|oracle_data| oracle_data.saved_at <= moment)].last()?;
+
+		Some((oracle_data.data.as_slice(), moment.saturating_sub(oracle_data.saved_at.clone())))
+	}
+
+	/// Read alive data starting from `cursor`, but no more than
+	/// `max_entries` entries & `max_bytes` bytes of payload
+	///
+	/// The first entry of a page is always returned, even if it's bigger
+	/// than `max_bytes`, otherwise such an entry would block the reading.
+	pub fn page(
+		&self,
+		now: MOMENT,
+		cursor: Option<Cursor<MOMENT>>,
+		max_entries: u32,
+		max_bytes: u32,
+	) -> Page<MOMENT> {
+		let alive = self.alive(&now);
+
+		let first_saved_at = |saved_at: &MOMENT| {
+			alive.partition_point(|oracle_data| &oracle_data.saved_at < saved_at)
+		};
+
+		let start = cursor.map_or(0, |Cursor { saved_at, skip }| {
+			let first = first_saved_at(&saved_at);
+			let same_moment =
+				alive[first..].partition_point(|oracle_data| oracle_data.saved_at == saved_at);
+			first + same_moment.min(skip as usize)
+		});
+
+		let mut data = Vec::new();
+		let mut bytes = 0usize;
+		for (index, oracle_data) in alive.iter().enumerate().skip(start) {
+			bytes = bytes.saturating_add(oracle_data.data.len());
+			if !data.is_empty() &&
+				(data.len() >= max_entries as usize || bytes > max_bytes as usize)
+			{
+				return Page {
+					data,
+					next: Some(Cursor {
+						saved_at: oracle_data.saved_at.clone(),
+						skip: (index - first_saved_at(&oracle_data.saved_at)) as u32,
+					}),
+				}
+			}
+			data.push(oracle_data.data.clone());
+		}
+
+		Page { data, next: None }
+	}
+
+	/// Check if storage contains outdated data
+	///
+	/// Only the oldest entry is checked, so it's cheap
+	pub fn has_outdated(&self, now: MOMENT) -> bool {
+		self.has_older_than(&now, &LIFETIME::get())
+	}
+
+	fn has_older_than(&self, now: &MOMENT, lifetime: &MOMENT) -> bool {
+		self.0
+			.first()
+			.map_or(false, |oracle_data| Self::is_older_than(now, &oracle_data.saved_at, lifetime))
+	}
+
+	fn ensure_not_historical(&self, now: &MOMENT) -> Result<(), Error> {
+		if matches!(self.0.last(), Some(OracleData { saved_at, .. }) if saved_at > now) {
+			return Err(Error::AttemptToInsertHistoricalData)
+		}
+
+		Ok(())
+	}
+
+	/// Delete data from storage if it's alive longer than LIFETIME
+	pub fn clean_outdated_data(&mut self, now: MOMENT) -> Result<(), Error> {
+		self.clean_outdated_data_bounded(now, u32::MAX)?;
+
+		Ok(())
+	}
+
+	/// Delete no more than `max_items` oldest entries alive longer than LIFETIME
+	///
+	/// Returns the amount of deleted entries
+	pub fn clean_outdated_data_bounded(
+		&mut self,
+		now: MOMENT,
+		max_items: u32,
+	) -> Result<u32, Error> {
+		self.clean_older_than(&now, &LIFETIME::get(), max_items)
+	}
+
+	fn clean_older_than(
+		&mut self,
+		now: &MOMENT,
+		lifetime: &MOMENT,
+		max_items: u32,
+	) -> Result<u32, Error> {
+		self.ensure_not_historical(now)?;
+
+		let point = self.outdated_point(now, lifetime).min(max_items as usize);
+		self.0.drain(..point);
+
+		Ok(point as u32)
+	}
+
+	/// Delete all data saved before `moment`, regardless of its lifetime
+	///
+	/// Unlike [`OracleStorage::clean_outdated_data`] doesn't depend on the current
+	/// moment, so it works even if the clock went backwards.
+	///
+	/// Returns the amount of deleted entries
+	pub fn clean_before(&mut self, moment: MOMENT) -> u32 {
+		let point = self.0.partition_point(|data| data.saved_at < moment);
+		self.0.drain(..point);
+
+		point as u32
+	}
+
+	/// Same as [`OracleStorage::clean_before`], but returns the deleted entries
+	/// in chronological order, e.g. so they can be archived
+	pub fn take_before(&mut self, moment: MOMENT) -> Vec<OracleData<MOMENT>> {
+		let point = self.0.partition_point(|data| data.saved_at < moment);
+		self.0.drain(..point).collect()
+	}
+
+	/// Push new data to storage & clean outdated data
+	pub fn push(&mut self, now: MOMENT, data: Data) -> Result<(), Error> {
+		// This call will also check that `now` is not obsolete
+		self.clean_outdated_data(now.clone())?;
+		self.0.push(OracleData { data, saved_at: now, confidence: None });
+
+		Ok(())
+	}
+
+	/// Push new data to storage without cleaning outdated data
+	///
+	/// Outdated data is still skipped by reads, but stays
+	/// in storage until [`OracleStorage::clean_outdated_data`] call.
+	pub fn append(&mut self, now: MOMENT, data: Data) -> Result<(), Error> {
+		self.append_with_confidence(now, data, None)
+	}
+
+	/// Same as [`OracleStorage::append`], with `confidence` of a numeric payload
+	pub fn append_with_confidence(
+		&mut self,
+		now: MOMENT,
+		data: Data,
+		confidence: Option<u128>,
+	) -> Result<(), Error> {
+		self.ensure_not_historical(&now)?;
+		self.0.push(OracleData { data, saved_at: now, confidence });
+
+		Ok(())
+	}
+}
+
+/// Outdated data kept for a `delay`, e.g. so it can still be audited
+impl<MOMENT: AtLeast32BitUnsigned + Clone + MaxEncodedLen, LIFETIME: Get<MOMENT>>
+	OracleStorage<MOMENT, LIFETIME>
+{
+	/// Data not outdated for `delay` yet, including alive data
+	pub fn iter_retained(&self, now: MOMENT, delay: MOMENT) -> impl Iterator<Item = &[u8]> {
+		self.0[self.outdated_point(&now, &LIFETIME::get().saturating_add(delay))..]
+			.iter()
+			.map(|oracle_data| oracle_data.data.as_slice())
+	}
+
+	/// Check if storage contains data outdated for `delay` already
+	///
+	/// Only the oldest entry is checked, so it's cheap
+	pub fn has_outdated_for(&self, now: MOMENT, delay: MOMENT) -> bool {
+		self.has_older_than(&now, &LIFETIME::get().saturating_add(delay))
+	}
+
+	/// Delete no more than `max_items` oldest entries outdated for `delay` already
+	///
+	/// Returns the amount of deleted entries
+	pub fn clean_outdated_data_for(
+		&mut self,
+		now: MOMENT,
+		delay: MOMENT,
+		max_items: u32,
+	) -> Result<u32, Error> {
+		self.clean_older_than(&now, &LIFETIME::get().saturating_add(delay), max_items)
+	}
+
+	/// Same as [`OracleStorage::clean_outdated_data_for`], but returns the deleted entries
+	/// in chronological order, e.g. so they can be archived
+	pub fn take_outdated_data_for(
+		&mut self,
+		now: MOMENT,
+		delay: MOMENT,
+		max_items: u32,
+	) -> Result<Vec<OracleData<MOMENT>>, Error> {
+		self.ensure_not_historical(&now)?;
+
+		let point = self
+			.outdated_point(&now, &LIFETIME::get().saturating_add(delay))
+			.min(max_items as usize);
+
+		Ok(self.0.drain(..point).collect())
+	}
+}
+
+impl<MOMENT: AtLeast32BitUnsigned + Clone + MaxEncodedLen, LIFETIME: Get<MOMENT>>
+	OracleStorage<MOMENT, LIFETIME>
+{
+	/// Numeric value at `moment`, linearly interpolated
+	/// between the alive entries around it
+	///
+	/// If there is an entry saved exactly at `moment`, its value is returned.
+	/// Returns `None` if any of the used entries isn't numeric (see [`decode_value`])
+	/// or `moment` isn't surrounded by alive entries.
+	pub fn value_interpolated_at(&self, now: MOMENT, moment: MOMENT) -> Option<u128> {
+		let alive = self.alive(&now);
+		let after_index = alive.partition_point(|oracle_data| oracle_data.saved_at <= moment);
+
+		let before = alive[..after_index].last()?;
+		let before_value = decode_value(&before.data)?;
+		if before.saved_at == moment {
+			return Some(before_value)
+		}
+
+		let after = alive.get(after_index)?;
+		let after_value = decode_value(&after.data)?;
+
+		let from: u128 = before.saved_at.clone().try_into().ok()?;
+		let at: u128 = moment.try_into().ok()?;
+		let to: u128 = after.saved_at.clone().try_into().ok()?;
+
+		Some(interpolate(before_value, after_value, at - from, to - from))
+	}
+}
+
+#[cfg(test)]
+mod oracle_data_test {
+	use super::{Cursor, OracleData};
+	use sp_core::ConstU64;
+
+	type OracleStorage = super::OracleStorage<u64, ConstU64<10>>;
+
+	#[test]
+	fn test_normal_push() {
+		let mut storage = OracleStorage::default();
+		storage.push(0, b"0".to_vec()).unwrap();
+		storage.push(1, b"1".to_vec()).unwrap();
+		storage.push(2, b"2".to_vec()).unwrap();
+
+		assert_eq!(
+			storage.0.as_slice(),
+			[
+				OracleData { saved_at: 0, data: b"0".to_vec(), confidence: None },
+				OracleData { saved_at: 1, data: b"1".to_vec(), confidence: None },
+				OracleData { saved_at: 2, data: b"2".to_vec(), confidence: None }
+			]
+		);
+	}
+
+	#[test]
+	fn test_introspection() {
+		let mut storage = OracleStorage::default();
+		assert!(storage.is_empty());
+		assert_eq!((storage.oldest(), storage.newest(), storage.total_bytes()), (None, None, 0));
+
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(20, b"20".to_vec()).unwrap();
+		assert_eq!(storage.len(), 2);
+		assert_eq!(
+			storage.oldest(),
+			Some(&OracleData { saved_at: 0, data: b"0".to_vec(), confidence: None })
+		);
+		assert_eq!(
+			storage.newest(),
+			Some(&OracleData { saved_at: 20, data: b"20".to_vec(), confidence: None })
+		);
+		// Outdated entries are counted too
+		assert_eq!(storage.total_bytes(), 3);
+	}
+
+	#[test]
+	fn test_sort() {
+		let mut storage = OracleStorage::default();
+		storage.0 = vec![
+			OracleData { saved_at: 2, data: b"2".to_vec(), confidence: None },
+			OracleData { saved_at: 0, data: b"0a".to_vec(), confidence: None },
+			OracleData { saved_at: 0, data: b"0b".to_vec(), confidence: None },
+		];
+		assert!(!storage.is_sorted());
+
+		storage.sort();
+		assert!(storage.is_sorted());
+		assert_eq!(
+			storage.0.as_slice(),
+			[
+				OracleData { saved_at: 0, data: b"0a".to_vec(), confidence: None },
+				OracleData { saved_at: 0, data: b"0b".to_vec(), confidence: None },
+				OracleData { saved_at: 2, data: b"2".to_vec(), confidence: None }
+			]
+		);
+		assert!(OracleStorage::default().is_sorted());
+	}
+
+	#[test]
+	fn test_moments() {
+		let mut storage = OracleStorage::default();
+		assert_eq!(storage.moments().count(), 0);
+
+		storage.append(0, b"0a".to_vec()).unwrap();
+		storage.append(0, b"0b".to_vec()).unwrap();
+		storage.append(2, b"2".to_vec()).unwrap();
+		let moments = storage.moments().collect::<Vec<_>>();
+		assert_eq!(
+			moments.iter().map(|(moment, run)| (*moment, run.len())).collect::<Vec<_>>(),
+			[(0, 2), (2, 1)]
+		);
+		assert_eq!(moments[0].1[1].data(), b"0b");
+
+		// Runs are joined back in order
+		let joined = moments
+			.into_iter()
+			.flat_map(|(_, run)| run.iter().cloned())
+			.collect::<OracleStorage>();
+		assert_eq!(joined, storage);
+	}
+
+	#[test]
+	fn test_insert_sorted() {
+		let mut storage = OracleStorage::default();
+		storage.insert_sorted(2, b"2".to_vec());
+		storage.insert_sorted(0, b"0".to_vec());
+		storage.insert_sorted(2, b"2b".to_vec());
+		storage.insert_sorted(1, b"1".to_vec());
+
+		assert_eq!(storage.iter_data(2).collect::<Vec<_>>(), [b"0".as_slice(), b"1", b"2", b"2b"]);
+	}
+
+	#[test]
+	fn test_merge_and_split_off() {
+		let mut storage = OracleStorage::default();
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(2, b"2".to_vec()).unwrap();
+
+		let mut other = OracleStorage::default();
+		other.append(1, b"1".to_vec()).unwrap();
+		other.append(2, b"2b".to_vec()).unwrap();
+		other.append(3, b"3".to_vec()).unwrap();
+
+		storage.merge(other);
+		assert!(storage.is_sorted());
+		assert_eq!(
+			storage.iter_data(3).collect::<Vec<_>>(),
+			[b"0".as_slice(), b"1", b"2", b"2b", b"3"]
+		);
+
+		let newer = storage.split_off(2);
+		assert_eq!(storage.iter_data(3).collect::<Vec<_>>(), [b"0".as_slice(), b"1"]);
+		assert_eq!(newer.iter_data(3).collect::<Vec<_>>(), [b"2".as_slice(), b"2b", b"3"]);
+		assert!(storage.split_off(10).is_empty());
+	}
+
+	#[test]
+	fn test_failed_insert() {
+		let mut storage = OracleStorage::default();
+		storage.push(10, b"0".to_vec()).unwrap();
+		assert_eq!(
+			storage.push(0, b"1".to_vec()).unwrap_err(),
+			super::Error::AttemptToInsertHistoricalData
+		);
+	}
+
+	#[test]
+	fn test_append() {
+		let mut storage = OracleStorage::default();
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(10, b"10".to_vec()).unwrap();
+		assert_eq!(
+			storage.0.as_slice(),
+			[
+				OracleData { saved_at: 0, data: b"0".to_vec(), confidence: None },
+				OracleData { saved_at: 10, data: b"10".to_vec(), confidence: None }
+			]
+		);
+		assert_eq!(storage.iter_data(10).collect::<Vec<_>>(), [b"10".as_slice()]);
+		assert_eq!(
+			storage.append(5, b"5".to_vec()).unwrap_err(),
+			super::Error::AttemptToInsertHistoricalData
+		);
+
+		storage.clean_outdated_data(10).unwrap();
+		assert_eq!(
+			storage.0.as_slice(),
+			[OracleData { saved_at: 10, data: b"10".to_vec(), confidence: None }]
+		);
+	}
+
+	#[test]
+	fn test_into_iter_data() {
+		let mut storage = OracleStorage::default();
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(5, b"5".to_vec()).unwrap();
+		storage.append(10, b"10".to_vec()).unwrap();
+
+		let borrowed = storage.iter_data(12).map(<[u8]>::to_vec).collect::<Vec<_>>();
+		assert_eq!(storage.into_iter_data(12).collect::<Vec<_>>(), borrowed);
+		assert_eq!(borrowed, [b"5".to_vec(), b"10".to_vec()]);
+	}
+
+	#[test]
+	fn test_bounded_clean() {
+		let mut storage = OracleStorage::default();
+		(0..5).for_each(|moment| storage.append(moment, moment.to_be_bytes().to_vec()).unwrap());
+
+		assert_eq!(storage.clean_outdated_data_bounded(13, 2), Ok(2));
+		assert_eq!(storage.0.len(), 3);
+		assert_eq!(storage.clean_outdated_data_bounded(13, 2), Ok(2));
+		assert_eq!(storage.clean_outdated_data_bounded(13, 2), Ok(0));
+		assert_eq!(
+			storage.0.as_slice(),
+			[OracleData { saved_at: 4, data: 4u64.to_be_bytes().to_vec(), confidence: None }]
+		);
+		assert_eq!(
+			storage.clean_outdated_data_bounded(3, 2),
+			Err(super::Error::AttemptToInsertHistoricalData)
+		);
+	}
+
+	#[test]
+	fn test_clean_before() {
+		let mut storage = OracleStorage::default();
+		(0..5).for_each(|moment| storage.append(moment, moment.to_be_bytes().to_vec()).unwrap());
+
+		assert_eq!(storage.clean_before(0), 0);
+		assert_eq!(storage.clean_before(3), 3);
+		assert_eq!(storage.clean_before(3), 0);
+		assert_eq!(
+			storage.0.as_slice(),
+			[
+				OracleData { saved_at: 3, data: 3u64.to_be_bytes().to_vec(), confidence: None },
+				OracleData { saved_at: 4, data: 4u64.to_be_bytes().to_vec(), confidence: None }
+			]
+		);
+		assert_eq!(storage.clean_before(100), 2);
+		assert!(storage.is_empty());
+	}
+
+	#[test]
+	fn test_take_before() {
+		let mut storage = OracleStorage::default();
+		(0..3).for_each(|moment| storage.append(moment, moment.to_be_bytes().to_vec()).unwrap());
+
+		assert_eq!(
+			storage.take_before(2),
+			[0u64, 1].map(|moment| OracleData {
+				saved_at: moment,
+				data: moment.to_be_bytes().to_vec(),
+				confidence: None
+			})
+		);
+		assert!(storage.take_before(2).is_empty());
+		assert_eq!(storage.len(), 1);
+	}
+
+	#[test]
+	fn test_has_outdated() {
+		let mut storage = OracleStorage::default();
+		assert!(!storage.has_outdated(100));
+
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(5, b"5".to_vec()).unwrap();
+		assert!(!storage.has_outdated(9));
+		assert!(storage.has_outdated(10));
+
+		storage.clean_outdated_data(10).unwrap();
+		assert!(!storage.has_outdated(10));
+	}
+
+	#[test]
+	fn test_take_outdated_data_for() {
+		let mut storage = OracleStorage::default();
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(5, b"5".to_vec()).unwrap();
+		storage.append(12, b"12".to_vec()).unwrap();
+
+		let taken = storage.take_outdated_data_for(15, 0, u32::MAX).unwrap();
+		assert_eq!(
+			taken.into_iter().map(|oracle_data| oracle_data.into_data()).collect::<Vec<_>>(),
+			[b"0".to_vec(), b"5".to_vec()]
+		);
+		assert_eq!(storage.len(), 1);
+		assert_eq!(
+			storage.take_outdated_data_for(10, 0, u32::MAX),
+			Err(super::Error::AttemptToInsertHistoricalData)
+		);
+	}
+
+	#[test]
+	fn test_moment_before_saved_at() {
+		let mut storage = OracleStorage::default();
+		storage.append(50, b"50".to_vec()).unwrap();
+
+		assert!(!storage.has_outdated(0));
+		assert!(!storage.has_outdated_for(0, u64::MAX));
+		assert_eq!(storage.iter_data(0).collect::<Vec<_>>(), [b"50".as_slice()]);
+		assert_eq!(storage.value_at_or_before(0, 50), Some((b"50".as_slice(), 0)));
+	}
+
+	#[test]
+	fn test_delayed_cleanup() {
+		let mut storage = OracleStorage::default();
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(5, b"5".to_vec()).unwrap();
+
+		assert!(storage.has_outdated(12));
+		assert!(!storage.has_outdated_for(12, 5));
+		assert!(storage.has_outdated_for(15, 5));
+		assert_eq!(storage.iter_data(15).count(), 0);
+		assert_eq!(storage.iter_retained(15, 5).collect::<Vec<_>>(), [b"5".as_slice()]);
+
+		assert_eq!(storage.clean_outdated_data_for(15, 5, u32::MAX), Ok(1));
+		assert_eq!(storage.len(), 1);
+	}
+
+	#[test]
+	fn test_lifetime() {
+		let mut storage = OracleStorage::default();
+		storage.push(0, b"0".to_vec()).unwrap();
+		storage.push(10, b"10".to_vec()).unwrap();
+		assert_eq!(
+			storage.0.as_slice(),
+			[OracleData { saved_at: 10, data: b"10".to_vec(), confidence: None }]
+		);
+
+		storage.push(100, b"100".to_vec()).unwrap();
+		assert_eq!(
+			storage.0.as_slice(),
+			[OracleData { saved_at: 100, data: b"100".to_vec(), confidence: None }]
+		);
+	}
+
+	#[test]
+	fn test_data_at_and_range() {
+		let mut storage = OracleStorage::default();
+		storage.push(0, b"0".to_vec()).unwrap();
+		storage.push(1, b"1a".to_vec()).unwrap();
+		storage.push(1, b"1b".to_vec()).unwrap();
+		storage.push(5, b"5".to_vec()).unwrap();
+
+		let data_at = |now, moment| storage.data_at(now, moment).collect::<Vec<_>>();
+		assert_eq!(data_at(5, 1), [b"1a", b"1b"]);
+		assert_eq!(data_at(5, 0), [b"0"]);
+		assert!(data_at(5, 3).is_empty());
+		assert!(data_at(10, 0).is_empty());
+
+		let range = |now, from, to| storage.range(now, from, to).collect::<Vec<_>>();
+		assert_eq!(range(5, 0, 5), [b"0".as_slice(), b"1a", b"1b"]);
+		assert_eq!(range(5, 1, 6), [b"1a".as_slice(), b"1b", b"5"]);
+		assert_eq!(range(10, 0, 6), [b"1a".as_slice(), b"1b", b"5"]);
+		assert!(range(5, 2, 5).is_empty());
+		assert!(range(5, 5, 0).is_empty());
+	}
+
+	#[test]
+	fn test_alive_at() {
+		let mut storage = OracleStorage::default();
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(5, b"5".to_vec()).unwrap();
+
+		let alive_at = |now, index| storage.alive_at(now, index).map(|entry| entry.data().to_vec());
+		assert_eq!(alive_at(9, 0), Some(b"0".to_vec()));
+		assert_eq!(alive_at(10, 0), None);
+		assert_eq!(alive_at(10, 1), Some(b"5".to_vec()));
+		assert_eq!(alive_at(10, 2), None);
+	}
+
+	#[test]
+	fn test_value_interpolated_at() {
+		use codec::Encode;
+
+		let mut storage = super::OracleStorage::<u64, ConstU64<100>>::default();
+		storage.append(0, 100u128.encode()).unwrap();
+		storage.append(10, 200u128.encode()).unwrap();
+		storage.append(20, 100u128.encode()).unwrap();
+		storage.append(30, b"not a number".to_vec()).unwrap();
+
+		let value_at = |now, moment| storage.value_interpolated_at(now, moment);
+		assert_eq!(value_at(30, 0), Some(100));
+		assert_eq!(value_at(30, 5), Some(150));
+		assert_eq!(value_at(30, 10), Some(200));
+		assert_eq!(value_at(30, 13), Some(170));
+		assert_eq!(value_at(30, 20), Some(100));
+		assert_eq!(value_at(30, 25), None);
+		assert_eq!(value_at(30, 31), None);
+		// Entry at 0 is outdated
+		assert_eq!(value_at(100, 5), None);
+
+		assert_eq!(super::interpolate(0, u128::MAX, 1, 2), u128::MAX / 2);
+		assert_eq!(super::interpolate(u128::MAX, 0, 1, 3), u128::MAX - u128::MAX / 3);
+	}
+
+	#[test]
+	fn test_value_at_or_before() {
+		let mut storage = OracleStorage::default();
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append(5, b"5a".to_vec()).unwrap();
+		storage.append(5, b"5b".to_vec()).unwrap();
+		storage.append(8, b"8".to_vec()).unwrap();
+
+		let value_at = |now, moment| storage.value_at_or_before(now, moment);
+		assert_eq!(value_at(8, 0), Some((b"0".as_slice(), 0)));
+		assert_eq!(value_at(8, 4), Some((b"0".as_slice(), 4)));
+		assert_eq!(value_at(8, 5), Some((b"5b".as_slice(), 0)));
+		assert_eq!(value_at(8, 7), Some((b"5b".as_slice(), 2)));
+		assert_eq!(value_at(8, 100), Some((b"8".as_slice(), 92)));
+		// Entry at 0 is outdated
+		assert_eq!(value_at(10, 4), None);
+	}
+
+	#[test]
+	fn test_page() {
+		let mut storage = OracleStorage::default();
+		storage.push(0, b"0".to_vec()).unwrap();
+		storage.push(1, b"1a".to_vec()).unwrap();
+		storage.push(1, b"1b".to_vec()).unwrap();
+		storage.push(2, b"2".to_vec()).unwrap();
+
+		let page = storage.page(10, None, 2, 100);
+		assert_eq!(page.data, [b"1a".to_vec(), b"1b".to_vec()]);
+		assert_eq!(page.next, Some(Cursor { saved_at: 2, skip: 0 }));
+
+		let page = storage.page(2, None, 2, 3);
+		assert_eq!(page.data, [b"0".to_vec(), b"1a".to_vec()]);
+		assert_eq!(page.next, Some(Cursor { saved_at: 1, skip: 1 }));
+
+		let page = storage.page(2, page.next, 2, 3);
+		assert_eq!(page.data, [b"1b".to_vec(), b"2".to_vec()]);
+		assert_eq!(page.next, None);
+
+		// Oversized entry is still returned alone
+		let page = storage.page(2, Some(Cursor { saved_at: 1, skip: 0 }), 2, 1);
+		assert_eq!(page.data, [b"1a".to_vec()]);
+		assert_eq!(page.next, Some(Cursor { saved_at: 1, skip: 1 }));
+	}
+
+	#[test]
+	fn test_append_with_confidence() {
+		let mut storage = OracleStorage::default();
+		storage.append(0, b"0".to_vec()).unwrap();
+		storage.append_with_confidence(1, b"1".to_vec(), Some(5)).unwrap();
+
+		assert_eq!(storage.oldest().unwrap().confidence(), None);
+		assert_eq!(storage.newest().unwrap().confidence(), Some(5));
+		assert!(storage.append_with_confidence(0, b"0".to_vec(), Some(5)).is_err());
+	}
+
+	#[test]
+	fn test_redact() {
+		let mut storage = OracleStorage::default();
+		storage.push(0, b"0".to_vec()).unwrap();
+		storage.push(1, b"1".to_vec()).unwrap();
+
+		assert_eq!(storage.redact(0), Some(b"0".to_vec()));
+		assert_eq!(storage.redact(2), None);
+		assert_eq!(storage.len(), 2);
+		assert_eq!(storage.iter_data(5).collect::<Vec<_>>(), [b"".as_slice(), b"1"]);
+	}
+}
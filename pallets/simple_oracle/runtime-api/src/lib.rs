@@ -0,0 +1,68 @@
+//! Runtime API definition for the simple oracle pallet
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_simple_oracle::{
+	feed::FeedId,
+	oracle_data::{Cursor, Page},
+	FeedMetadata, OracleHealth, ReadCost, RejectReason,
+};
+
+sp_api::decl_runtime_apis! {
+	/// API to read the simple oracle pallet data
+	pub trait SimpleOracleApi<Moment, AccountId, Entry>
+	where
+		Moment: Codec,
+		AccountId: Codec,
+		Entry: Codec,
+	{
+		/// Read alive oracle data in chronological order
+		///
+		/// The response is bounded by the pallet's query limits,
+		/// pass [`Page::next`] back to read the rest of the data.
+		fn oracle_data(cursor: Option<Cursor<Moment>>) -> Option<Page<Moment>>;
+
+		/// Estimate the cost of reading alive oracle data saved in `[from, to)`
+		///
+		/// Based on the amount and total bytes of the read entries
+		/// and on the size of the whole storage.
+		fn read_cost(from: Moment, to: Moment) -> ReadCost;
+
+		/// Health metrics of the feed for external monitoring
+		fn health() -> OracleHealth<AccountId, Moment>;
+
+		/// Feed description, to decode its payloads
+		fn feed_metadata() -> FeedMetadata;
+
+		/// Read alive entries in chronological order, skipping other payloads
+		///
+		/// Paged like [`oracle_data`](SimpleOracleApi::oracle_data), pass
+		/// [`Page::next`] back to read the rest of the entries.
+		fn oracle_entries(cursor: Option<Cursor<Moment>>) -> Option<Page<Moment, Entry>>;
+
+		/// Read archived oracle data saved in `[from, to)` in chronological order
+		///
+		/// The archive keeps data pruned from the alive window for audits
+		///
+		/// Paged like [`oracle_data`](SimpleOracleApi::oracle_data), pass
+		/// [`Page::next`] back to read the rest of the data.
+		fn archived_range(from: Moment, to: Moment, cursor: Option<Cursor<Moment>>) -> Page<Moment>;
+
+		/// Root of the MMR of all entries stored so far, see `pallet_simple_oracle::mmr`
+		fn mmr_root() -> Option<Block::Hash>;
+
+		/// Storage keys to prove the latest value with
+		///
+		/// Pass them to `state_getReadProof` at the same block to get the read proof
+		fn latest_value_keys() -> Vec<Vec<u8>>;
+
+		/// Check whether `data` pushed by `who` for `feed` at `moment` would be accepted
+		///
+		/// Evaluates every check of a push without writing, so feeders can pre-flight
+		/// submissions and avoid wasted fees.
+		fn would_accept(who: AccountId, feed: FeedId, data: Vec<u8>, moment: Moment) -> Result<(), RejectReason>;
+	}
+}
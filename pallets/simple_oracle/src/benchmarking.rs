@@ -0,0 +1,410 @@
+//! Benchmarking setup for pallet-simple-oracle
+//!
+//! Storage is pre-filled with [`Config::MaxDataLen`] payloads up to
+//! [`Config::MaxEntries`], so weights cover the worst case, not an empty storage.
+
+use super::*;
+
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{
+	traits::{Hash, Saturating},
+	KeyTypeId,
+};
+use sp_std::{vec, vec::Vec};
+
+use crate::traits::BenchmarkHelper;
+
+type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
+
+/// Key type of the generated guardian keys
+const GUARDIAN_KEY_TYPE: KeyTypeId = KeyTypeId(*b"gdns");
+/// Size of the Wormhole guardian set
+const MAX_GUARDIANS: u32 = 19;
+/// Size of a VAA body with an empty payload
+const VAA_BODY_LEN: usize = 4 + 4 + 2 + 32 + 8 + 1;
+
+/// Payload of `len` bytes, distinct for distinct `seed`s if `len` allows
+fn payload(seed: u32, len: u32) -> oracle_data::Data {
+	let mut data = vec![0; len as usize];
+	let seed = seed.to_le_bytes();
+	let prefix = seed.len().min(data.len());
+	data[..prefix].copy_from_slice(&seed[..prefix]);
+	data
+}
+
+/// Fill [`FeedEntries`] with `outdated` entries followed by `fresh` entries
+///
+/// Every entry is saved at its own moment, has its own key & payload indexed by its hash,
+/// as in the worst case. The lifetime & the current moment are set, so that exactly
+/// the `outdated` entries are cleaned.
+fn fill_storage<T: Config>(outdated: u32, fresh: u32) {
+	for moment in 0..outdated.saturating_add(fresh) {
+		let data = payload(moment, T::MaxDataLen::get());
+		Pallet::<T>::append_entry(&OracleEntryOf::<T>::new(data, moment.into(), None));
+	}
+
+	<Lifetime<T>>::put(MomentOf::<T>::from(fresh.saturating_add(1)));
+	let now =
+		MomentOf::<T>::from(outdated.saturating_add(fresh)).saturating_add(T::PruneDelay::get());
+	<pallet_timestamp::Now<T>>::put(now);
+}
+
+/// `n` entries pushed at the current moment & released at `release_at`
+fn queue<T: Config>(n: u32, release_at: MomentOf<T>) -> Vec<Embargoed<MomentOf<T>>> {
+	let now = <pallet_timestamp::Pallet<T>>::get();
+	(0..n)
+		.map(|seed| Embargoed {
+			release_at,
+			saved_at: now,
+			data: payload(seed, T::MaxDataLen::get()),
+			confidence: None,
+		})
+		.collect()
+}
+
+/// Fill [`EmbargoedData`] with `n` entries not released yet, so a push is embargoed
+/// & decodes the whole queue
+fn fill_embargoed<T: Config>(n: u32) {
+	let release_at = <pallet_timestamp::Pallet<T>>::get().saturating_add(1u32.into());
+	<EmbargoedData<T>>::put(queue::<T>(n, release_at));
+}
+
+fn stored_len<T: Config>() -> usize {
+	Pallet::<T>::stored_entries() as usize
+}
+
+/// Amount of stored entries & entries waiting for their release
+fn pushed_len<T: Config>() -> usize {
+	stored_len::<T>() +
+		<EmbargoedData<T>>::decode_len().unwrap_or_default() +
+		<TimelockedData<T>>::decode_len().unwrap_or_default()
+}
+
+benchmarks! {
+	push_data {
+		let n in 0 .. T::MaxEntries::get() - 1;
+		let l in 0 .. T::MaxDataLen::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		<Authority<T>>::put(&caller);
+
+		fill_embargoed::<T>(n);
+	}: _(RawOrigin::Signed(caller), vec![0; l as usize])
+	verify {
+		assert_eq!(pushed_len::<T>(), n as usize + 1);
+	}
+
+	push_data_fast {
+		let n in 0 .. T::MaxEntries::get() - 1;
+		let l in 0 .. T::MaxDataLen::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		<Authority<T>>::put(&caller);
+
+		fill_embargoed::<T>(n);
+	}: _(RawOrigin::Signed(caller), vec![0; l as usize])
+	verify {
+		assert_eq!(pushed_len::<T>(), n as usize + 1);
+	}
+
+	clean_outdated_data {
+		let n in 0 .. T::MaxEntries::get();
+
+		fill_storage::<T>(n, T::MaxEntries::get() - n);
+
+		let origin = T::CleanOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, n)
+	verify {
+		assert_eq!(stored_len::<T>(), (T::MaxEntries::get() - n) as usize);
+	}
+
+	set_lifetime {
+		let lifetime = MomentOf::<T>::from(1u32);
+		let origin = T::SetLifetimeOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, lifetime)
+	verify {
+		assert_eq!(<Lifetime<T>>::get(), Some(lifetime));
+	}
+
+	set_retention_override {
+		let origin = T::SetLifetimeOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, 1u32.into(), 1u32.into())
+	verify {
+		assert!(<RetentionOverride<T>>::exists());
+	}
+
+	set_authority {
+		let authority: T::AccountId = account("authority", 0, 0);
+		<PendingAuthority<T>>::put(&authority);
+
+		let origin = T::SetAuthorityOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, authority.clone())
+	verify {
+		assert!(
+			<Authority<T>>::get() == Some(authority.clone()) ||
+				<ScheduledAuthority<T>>::get().map(|(_, scheduled)| scheduled) == Some(authority)
+		);
+	}
+
+	set_paused {
+		let origin = T::PauseOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, true)
+	verify {
+		assert!(<Paused<T>>::get());
+	}
+
+	force_clean {
+		let n in 0 .. T::MaxEntries::get();
+
+		fill_storage::<T>(n, T::MaxEntries::get() - n);
+	}: _(RawOrigin::Root, n.into())
+	verify {
+		assert_eq!(stored_len::<T>(), (T::MaxEntries::get() - n) as usize);
+	}
+
+	release_data {
+		let n in 0 .. T::MaxEntries::get();
+
+		// Both queues hold due entries, the rest of full `EmbargoedData` isn't due yet
+		let now = <pallet_timestamp::Pallet<T>>::get();
+		let timelocked = n / 2;
+		let mut embargoed = queue::<T>(n - timelocked, now);
+		embargoed.extend(queue::<T>(T::MaxEntries::get() - n, now.saturating_add(1u32.into())));
+		<EmbargoedData<T>>::put(embargoed);
+		<TimelockedData<T>>::put(queue::<T>(timelocked, now));
+	}: {
+		Pallet::<T>::release(now);
+	}
+	verify {
+		assert_eq!(stored_len::<T>(), n as usize);
+	}
+
+	push_data_timelocked {
+		let n in 0 .. T::MaxEntries::get() - 1;
+		let l in 0 .. T::MaxDataLen::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		<Authority<T>>::put(&caller);
+
+		// Released before every timelocked entry, so it's inserted in front of them
+		let now = <pallet_timestamp::Pallet<T>>::get();
+		<TimelockedData<T>>::put(queue::<T>(n, now.saturating_add(1u32.into())));
+	}: _(RawOrigin::Signed(caller), vec![0; l as usize], now)
+	verify {
+		assert_eq!(<TimelockedData<T>>::decode_len(), Some(n as usize + 1));
+	}
+
+	announce_push {
+		let caller: T::AccountId = whitelisted_caller();
+		<Authority<T>>::put(&caller);
+	}: _(RawOrigin::Signed(caller), T::Hashing::hash(&[]))
+	verify {
+		assert!(<AnnouncedPush<T>>::exists());
+	}
+
+	read_data {
+		let s in 0 .. T::MaxEntries::get();
+		let n in 0 .. T::MaxEntries::get();
+		let l in 0 .. T::MaxDataLen::get();
+
+		// The newest `n` entries are read, the newest one holds all `l` bytes
+		let stored = s.max(n);
+		for moment in 0..stored {
+			let len = if moment + 1 == stored { l } else { 0 };
+			Pallet::<T>::append_entry(&OracleEntryOf::<T>::new(
+				payload(moment, len),
+				moment.into(),
+				None,
+			));
+		}
+		<Lifetime<T>>::put(MomentOf::<T>::from(stored.saturating_add(1)));
+		<pallet_timestamp::Now<T>>::put(MomentOf::<T>::from(stored));
+
+		let (from, to) = (MomentOf::<T>::from(stored - n), MomentOf::<T>::from(stored));
+	}: {
+		Pallet::<T>::oracle_data_range(from, to);
+	}
+	verify {
+		assert_eq!(Pallet::<T>::oracle_data_range(from, to).map(|data| data.len()), Some(n as usize));
+	}
+
+	resort_storage {
+		let n in 0 .. T::MaxEntries::get();
+
+		// Entries in reverse chronological order, all indexed & redacted
+		let feed = T::Feed::get();
+		for sequence in 0..n {
+			let data = payload(sequence, T::MaxDataLen::get());
+			let hash = T::Hashing::hash(&data);
+			let entry = OracleEntryOf::<T>::new(data, (n - sequence).into(), None);
+			<FeedEntries<T>>::insert(feed, SequenceId::from(sequence), entry);
+			<HashIndex<T>>::insert(hash, SequenceId::from(sequence));
+			<Redacted<T>>::insert(SequenceId::from(sequence), hash);
+		}
+		<NextSequence<T>>::put(SequenceId::from(n));
+		<EntryCount<T>>::put(n);
+		<Corrupted<T>>::put(true);
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(!<Corrupted<T>>::get());
+		assert!(Pallet::<T>::load_storage().map_or(true, |storage| storage.is_sorted()));
+	}
+
+	transfer_feed_ownership {
+		let caller: T::AccountId = whitelisted_caller();
+		<Authority<T>>::put(&caller);
+
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+	}: _(RawOrigin::Signed(caller), new_owner.clone())
+	verify {
+		assert_eq!(<PendingAuthority<T>>::get(), Some(new_owner));
+	}
+
+	accept_feed_ownership {
+		let caller: T::AccountId = whitelisted_caller();
+		<PendingAuthority<T>>::put(&caller);
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert!(!<PendingAuthority<T>>::exists());
+	}
+
+	export_snapshot {
+		let n in 0 .. T::MaxEntries::get();
+
+		fill_storage::<T>(0, n);
+	}: _(RawOrigin::Root, 0)
+
+	import_snapshot {
+		let n in 0 .. T::MaxEntries::get();
+		let l in 1 .. T::SnapshotChunkLen::get().max(1);
+
+		// The last `l` bytes of a snapshot of `n` entries complete the import
+		let mut storage = OracleStorageOf::<T>::default();
+		for moment in 0..n {
+			storage
+				.append(moment.into(), payload(moment, T::MaxDataLen::get()))
+				.expect("entries are saved in order");
+		}
+		let snapshot = storage.encode();
+		let hash = T::Hashing::hash(&snapshot);
+		let (pending, chunk) = snapshot.split_at(snapshot.len().saturating_sub(l as usize));
+		<SnapshotImport<T>>::put((hash, pending.to_vec()));
+		let chunk = chunk.to_vec();
+
+		// Replaced storage is full
+		fill_storage::<T>(0, T::MaxEntries::get());
+	}: _(RawOrigin::Root, hash, chunk)
+	verify {
+		assert_eq!(stored_len::<T>(), n as usize);
+	}
+
+	redact_entry {
+		fill_storage::<T>(0, T::MaxEntries::get());
+
+		// The newest entry is cached in `LatestValue` as well
+		let sequence = Pallet::<T>::last_sequence().expect("storage is full");
+		let origin = T::RedactOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, sequence)
+	verify {
+		assert!(<Redacted<T>>::contains_key(sequence));
+	}
+
+	relay_band_result {
+		let n in 0 .. T::MaxEntries::get() - 1;
+		let l in 0 .. T::MaxDataLen::get();
+
+		let relayer = T::BenchmarkHelper::band_relayer();
+		let report = T::BenchmarkHelper::report(l);
+
+		fill_embargoed::<T>(n);
+	}: _(RawOrigin::Signed(relayer), 0, T::MinBandAnswers::get(), report)
+	verify {
+		assert_eq!(pushed_len::<T>(), n as usize + 1);
+	}
+
+	// Only the VAA is verified, its payload is pushed as by `push_data`
+	submit_vaa {
+		let n in 1 .. MAX_GUARDIANS;
+
+		// Every guardian of the set signs, so every signature is recovered
+		let body = vec![0; VAA_BODY_LEN];
+		let digest = wormhole::digest(&body);
+		let mut vaa = vec![wormhole::VAA_VERSION];
+		vaa.extend(0u32.to_be_bytes());
+		vaa.push(n as u8);
+		let mut addresses = Vec::new();
+		for index in 0..n {
+			let public = sp_io::crypto::ecdsa_generate(GUARDIAN_KEY_TYPE, None);
+			let signature =
+				sp_io::crypto::ecdsa_sign_prehashed(GUARDIAN_KEY_TYPE, &public, &digest)
+					.expect("the key is generated in the keystore");
+			let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &digest)
+				.expect("the signature is valid");
+			addresses.push(wormhole::guardian_address(&public));
+			vaa.push(index as u8);
+			vaa.extend(signature.0);
+		}
+		vaa.extend(body);
+
+		let guardians = wormhole::GuardianSet { index: 0, addresses };
+	}: {
+		let _ = wormhole::verify(&vaa, &guardians);
+	}
+	verify {
+		assert!(wormhole::verify(&vaa, &guardians).is_ok());
+	}
+
+	prune_archive {
+		let n in 0 .. T::MaxArchiveDepth::get();
+
+		// Every archived moment is expired
+		let len = T::MaxDataLen::get();
+		let moments = (0..n).map(MomentOf::<T>::from).collect::<Vec<_>>();
+		for (seed, moment) in (0..).zip(&moments) {
+			<Archive<T>>::insert(moment, vec![payload(seed, len)]);
+		}
+		<ArchivedMoments<T>>::put(BoundedVec::truncate_from(moments));
+		<ArchivedBytes<T>>::put(n.saturating_mul(len));
+
+		let now = MomentOf::<T>::from(n)
+			.saturating_add(Pallet::<T>::lifetime())
+			.saturating_add(T::PruneDelay::get())
+			.saturating_add(T::ArchiveWindow::get());
+		<pallet_timestamp::Now<T>>::put(now);
+	}: {
+		Pallet::<T>::prune_archive(n);
+	}
+	verify {
+		assert!(<ArchivedMoments<T>>::get().is_empty());
+	}
+
+	delete_feed_data {
+		let n in 0 .. T::MaxEntries::get();
+
+		// Every entry indexed, redacted & pushed by its own account, full queues
+		fill_storage::<T>(0, n);
+		for sequence in 0..n {
+			let pusher: T::AccountId = account("pusher", sequence, 0);
+			<LastPushedId<T>>::insert(pusher, SequenceId::from(sequence));
+			<Redacted<T>>::insert(SequenceId::from(sequence), T::Hashing::hash_of(&sequence));
+		}
+		let release_at = <pallet_timestamp::Pallet<T>>::get().saturating_add(1u32.into());
+		<EmbargoedData<T>>::put(queue::<T>(T::MaxEntries::get(), release_at));
+		<TimelockedData<T>>::put(queue::<T>(T::MaxEntries::get(), release_at));
+		<PendingPrunes<T>>::put(n);
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(Pallet::<T>::is_storage_empty());
+		assert_eq!(pushed_len::<T>(), 0);
+		assert_eq!(<HashIndex<T>>::iter().count(), 0);
+		assert_eq!(<LastPushedId<T>>::iter().count(), 0);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
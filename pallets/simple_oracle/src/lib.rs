@@ -1,6 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub use audit::RecordRejectedPushes;
 pub use pallet::*;
+pub use proxy::OracleProxy;
 
 #[cfg(test)]
 mod mock;
@@ -8,258 +10,4127 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-// Module defining storage structures for oracle data
-pub mod oracle_data {
-	use core::ops::Sub;
-	use sp_std::vec::Vec;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+// Storage structures for oracle data, reusable without the pallet
+pub use simple_oracle_data as oracle_data;
 
-	use frame_support::pallet_prelude::{Decode, Encode, Get, RuntimeDebug};
+// Module defining identifiers of price feeds
+pub mod feed {
+	use frame_support::pallet_prelude::{Decode, Encode, MaxEncodedLen, RuntimeDebug};
 	use scale_info::TypeInfo;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+
+	pub const ASSET_SYMBOL_LEN: usize = 8;
+
+	/// Asset ticker symbol, zero-padded to [`ASSET_SYMBOL_LEN`] bytes
+	#[derive(
+		RuntimeDebug,
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		PartialOrd,
+		Ord,
+		TypeInfo,
+	)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct Asset([u8; ASSET_SYMBOL_LEN]);
+
+	impl Asset {
+		/// Asset with `symbol` ticker
+		///
+		/// # Panics
+		///
+		/// If `symbol` is longer than [`ASSET_SYMBOL_LEN`]. Intended for constants,
+		/// use [`Asset::try_new`] for runtime values.
+		pub const fn new(symbol: &[u8]) -> Self {
+			match Self::try_new(symbol) {
+				Some(asset) => asset,
+				None => panic!("asset symbol is too long"),
+			}
+		}
+
+		/// Asset with `symbol` ticker, if it's not longer than [`ASSET_SYMBOL_LEN`]
+		pub const fn try_new(symbol: &[u8]) -> Option<Self> {
+			if symbol.len() > ASSET_SYMBOL_LEN {
+				return None
+			}
+
+			let mut bytes = [0u8; ASSET_SYMBOL_LEN];
+			let mut index = 0;
+			while index < symbol.len() {
+				bytes[index] = symbol[index];
+				index += 1;
+			}
+
+			Some(Self(bytes))
+		}
+
+		/// Ticker symbol without padding
+		pub fn symbol(&self) -> &[u8] {
+			let len = self.0.iter().position(|byte| *byte == 0).unwrap_or(ASSET_SYMBOL_LEN);
+			&self.0[..len]
+		}
+	}
+
+	/// Price feed identifier, e.g. BTC/USD
+	///
+	/// Values of the feed are prices of one `base` asset in `quote` assets
+	#[derive(
+		RuntimeDebug,
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		PartialOrd,
+		Ord,
+		TypeInfo,
+	)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct FeedId {
+		pub base: Asset,
+		pub quote: Asset,
+	}
 
-	pub type Data = Vec<u8>;
+	impl FeedId {
+		pub const fn new(base: Asset, quote: Asset) -> Self {
+			Self { base, quote }
+		}
 
-	#[derive(RuntimeDebug, Encode, Decode, Default, Clone, PartialEq, TypeInfo)]
-	pub struct OracleData<MOMENT> {
-		data: Data,
-		saved_at: MOMENT,
+		/// Feed of `base`/`quote` ticker symbols
+		///
+		/// # Panics
+		///
+		/// If any symbol is longer than [`ASSET_SYMBOL_LEN`], see [`Asset::new`]
+		pub const fn pair(base: &[u8], quote: &[u8]) -> Self {
+			Self::new(Asset::new(base), Asset::new(quote))
+		}
 	}
 
-	impl<MOMENT: PartialOrd> PartialOrd for OracleData<MOMENT> {
-		fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-			self.saved_at.partial_cmp(&other.saved_at)
+	#[cfg(test)]
+	mod feed_test {
+		use super::{Asset, FeedId, ASSET_SYMBOL_LEN};
+
+		#[test]
+		fn test_asset() {
+			assert_eq!(Asset::new(b"BTC").symbol(), b"BTC");
+			assert_eq!(Asset::new(b"").symbol(), b"");
+			assert_eq!(Asset::new(b"12345678").symbol(), b"12345678");
+			assert_eq!(Asset::try_new(&[1; ASSET_SYMBOL_LEN + 1]), None);
+		}
+
+		#[test]
+		fn test_feed_id() {
+			const FEED: FeedId = FeedId::pair(b"BTC", b"USD");
+			assert_eq!(FEED, FeedId::new(Asset::new(b"BTC"), Asset::new(b"USD")));
+			assert_eq!(FEED.base.symbol(), b"BTC");
+			assert_eq!(FEED.quote.symbol(), b"USD");
+			assert_ne!(FEED, FeedId::pair(b"USD", b"BTC"));
 		}
 	}
+}
+
+pub mod traits {
+	use super::{feed::FeedId, price::Price};
+
+	/// Source of prices of a single feed
+	pub trait PriceProvider<Moment> {
+		/// Asset pair of the prices
+		fn feed_id() -> FeedId;
+
+		/// The newest price together with its age
+		fn latest_price() -> Option<(Price, Moment)>;
+	}
+
+	/// Setup of benchmarks the runtime has to provide
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<AccountId> {
+		/// Account contained in [`Config::BandRelayers`](crate::Config::BandRelayers)
+		fn band_relayer() -> AccountId;
+
+		/// Report accepted by [`Config::ReportDecoder`](crate::Config::ReportDecoder),
+		/// with a payload of `data_len` bytes if the format allows
+		fn report(data_len: u32) -> sp_std::vec::Vec<u8>;
+	}
+}
 
-	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, TypeInfo, Default)]
-	pub struct OracleStorage<MOMENT>(Vec<OracleData<MOMENT>>);
+// Module with helpers to read numeric payloads as prices
+pub mod price {
+	use frame_support::pallet_prelude::{Decode, Encode, MaxEncodedLen, RuntimeDebug};
+	use scale_info::TypeInfo;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_runtime::{FixedPointNumber, FixedU128};
+
+	use super::{feed::FeedId, oracle_data, traits::PriceProvider};
+
+	/// Price decoded from a numeric payload
+	///
+	/// Numeric payloads hold an integer amount of the smallest units,
+	/// and `decimals` tells how many decimal places these units have.
+	#[derive(
+		RuntimeDebug,
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		PartialOrd,
+		Ord,
+		TypeInfo,
+	)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct Price(pub FixedU128);
 
-	#[derive(Debug, PartialEq, Eq)]
-	pub enum Error {
-		/// An attempt was made to insert outdated data
+	impl Price {
+		/// Price of `units` smallest units with `decimals` decimal places
 		///
-		/// For simplicity, you can only add data to a storage
-		/// if there is no newer data in it.
-		AttemptToInsertHistoricalData,
+		/// Returns `None` if the price doesn't fit into [`FixedU128`]
+		pub fn from_units(units: u128, decimals: u8) -> Option<Self> {
+			FixedU128::checked_from_rational(units, 10u128.checked_pow(decimals.into())?).map(Self)
+		}
+
+		/// Decode price from a numeric payload, see [`oracle_data::decode_value`]
+		pub fn from_payload(data: &[u8], decimals: u8) -> Option<Self> {
+			Self::from_units(oracle_data::decode_value(data)?, decimals)
+		}
+
+		pub fn into_inner(self) -> FixedU128 {
+			self.0
+		}
+	}
+
+	impl From<FixedU128> for Price {
+		fn from(value: FixedU128) -> Self {
+			Self(value)
+		}
 	}
 
-	impl<MOMENT: Sub<MOMENT> + Copy + Ord> OracleStorage<MOMENT>
+	/// Cross rate of two feeds with the same quote asset
+	///
+	/// E.g. BTC/EUR from BTC/USD & EUR/USD feeds. Returns `None` if quote assets
+	/// differ, any of the prices is missing or older than `max_age`, or the rate
+	/// doesn't fit into [`FixedU128`].
+	pub fn cross_rate<Moment, Base, Quote>(max_age: Moment) -> Option<(FeedId, Price)>
 	where
-		<MOMENT as Sub>::Output: PartialOrd<MOMENT>,
+		Moment: PartialOrd,
+		Base: PriceProvider<Moment>,
+		Quote: PriceProvider<Moment>,
 	{
-		pub fn iter_data<LIFETIME>(&self, now: MOMENT) -> impl Iterator<Item = &[u8]>
-		where
-			LIFETIME: Get<MOMENT>,
-		{
-			self.0
-				.iter()
-				.skip_while(move |oracle_data| now.sub(oracle_data.saved_at).ge(&LIFETIME::get()))
-				.map(|oracle_data| oracle_data.data.as_slice())
+		let (base_feed, quote_feed) = (Base::feed_id(), Quote::feed_id());
+		if base_feed.quote != quote_feed.quote {
+			return None
 		}
 
-		/// Delete data from storage if it's alive longer than LIFETIME
-		pub fn clean_outdated_data<LIFETIME>(&mut self, now: MOMENT) -> Result<(), Error>
-		where
-			LIFETIME: Get<MOMENT>,
-		{
-			if matches!(self.0.last(), Some(OracleData { saved_at, .. }) if saved_at > &now) {
-				return Err(Error::AttemptToInsertHistoricalData)
-			}
+		let fresh_price = |(price, age): (Price, Moment)| (age <= max_age).then_some(price);
+		let base_price = Base::latest_price().and_then(fresh_price)?;
+		let quote_price = Quote::latest_price().and_then(fresh_price)?;
+
+		Some((
+			FeedId::new(base_feed.base, quote_feed.base),
+			Price(base_price.0.checked_div(&quote_price.0)?),
+		))
+	}
+
+	#[cfg(test)]
+	mod price_test {
+		use super::Price;
+		use codec::Encode;
+		use sp_runtime::{FixedPointNumber, FixedU128};
+
+		#[test]
+		fn test_from_units() {
+			assert_eq!(
+				Price::from_units(12_345, 2),
+				Some(Price(FixedU128::saturating_from_rational(12_345, 100)))
+			);
+			assert_eq!(Price::from_units(7, 0), Some(Price(FixedU128::from_u32(7))));
+			assert_eq!(Price::from_units(1, 39), None);
+			assert_eq!(Price::from_units(u128::MAX, 0), None);
+		}
+
+		#[test]
+		fn test_from_payload() {
+			assert_eq!(
+				Price::from_payload(&150u128.encode(), 1),
+				Some(Price(FixedU128::from_u32(15)))
+			);
+			assert_eq!(Price::from_payload(&150u64.encode(), 1), None);
+			assert_eq!(Price::from_payload(b"", 1), None);
+		}
+	}
+}
+
+pub mod weights;
+
+pub mod audit {
+	use frame_support::{pallet_prelude::*, traits::IsSubType};
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_runtime::traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension};
+
+	use super::pallet::{Call, Config, Pallet};
+
+	/// Push rejected by the pallet
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct RejectedPush<AccountId, Moment> {
+		pub who: AccountId,
+		pub reason: DispatchError,
+		pub moment: Moment,
+	}
+
+	/// Record pushes rejected by the pallet to [`RejectedPushes`](super::RejectedPushes)
+	///
+	/// Storage changes of a failed call are reverted, so the record is made
+	/// in [`SignedExtension::post_dispatch`], which runs outside of the call.
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct RecordRejectedPushes<T: Config + Send + Sync>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config + Send + Sync> RecordRejectedPushes<T> {
+		pub fn new() -> Self {
+			Self(sp_std::marker::PhantomData)
+		}
+	}
 
-			let point = self.0.partition_point(|data| now.sub(data.saved_at).ge(&LIFETIME::get()));
-			self.0.drain(..point);
+	impl<T: Config + Send + Sync> sp_std::fmt::Debug for RecordRejectedPushes<T> {
+		fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+			write!(f, "RecordRejectedPushes")
+		}
+	}
+
+	impl<T: Config + Send + Sync> SignedExtension for RecordRejectedPushes<T>
+	where
+		<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	{
+		const IDENTIFIER: &'static str = "RecordRejectedPushes";
+		type AccountId = T::AccountId;
+		type Call = <T as frame_system::Config>::RuntimeCall;
+		type AdditionalSigned = ();
+		/// Submitter of the push, if the call is a push
+		type Pre = Option<T::AccountId>;
 
+		fn additional_signed(&self) -> Result<(), TransactionValidityError> {
 			Ok(())
 		}
-		/// Push new data to storage & clean outdated data
-		pub fn push<LIFETIME>(&mut self, now: MOMENT, data: Data) -> Result<(), Error>
-		where
-			LIFETIME: Get<MOMENT>,
-		{
-			// This call will also check that `now` is not obsolete
-			self.clean_outdated_data::<LIFETIME>(now)?;
-			self.0.push(OracleData { data, saved_at: now });
 
+		fn pre_dispatch(
+			self,
+			who: &Self::AccountId,
+			call: &Self::Call,
+			_info: &DispatchInfoOf<Self::Call>,
+			_len: usize,
+		) -> Result<Self::Pre, TransactionValidityError> {
+			Ok(matches!(
+				call.is_sub_type(),
+				Some(Call::push_data { .. }) |
+					Some(Call::push_data_fast { .. }) |
+					Some(Call::push_data_with_confidence { .. }) |
+					Some(Call::push_entry { .. }) |
+					Some(Call::push_data_timelocked { .. }) |
+					Some(Call::push_data_observed { .. }) |
+					Some(Call::submit_vaa { .. }) |
+					Some(Call::relay_band_result { .. }) |
+					Some(Call::execute_push { .. }) |
+					Some(Call::push_data_as { .. })
+			)
+			.then(|| who.clone()))
+		}
+
+		fn post_dispatch(
+			pre: Option<Self::Pre>,
+			_info: &DispatchInfoOf<Self::Call>,
+			_post_info: &PostDispatchInfoOf<Self::Call>,
+			_len: usize,
+			result: &DispatchResult,
+		) -> Result<(), TransactionValidityError> {
+			if let (Some(Some(who)), Err(reason)) = (pre, result) {
+				Pallet::<T>::record_rejected_push(who, *reason);
+			}
 			Ok(())
 		}
 	}
+}
+
+pub mod proxy {
+	use frame_support::{pallet_prelude::*, traits::InstanceFilter};
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+
+	use super::pallet::{Call, Config};
+
+	/// Proxy type for delegating oracle operations via `pallet-proxy`
+	///
+	/// Lets the [`authority`](super::Pallet::authority) cold key delegate only
+	/// pushes to a hot key. The runtime proxy type filters the calls of this
+	/// pallet with it, e.g. `RuntimeCall::SimpleOracleModule(call) => proxy.filter(call)`
+	#[derive(
+		Copy,
+		Clone,
+		Eq,
+		PartialEq,
+		Ord,
+		PartialOrd,
+		Default,
+		Encode,
+		Decode,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+	)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum OracleProxy {
+		/// Any call of the pallet
+		#[default]
+		Any,
+		/// Only [`push_data`](super::Pallet::push_data) and its variants,
+		/// including announced pushes
+		Push,
+	}
+
+	impl<T: Config> InstanceFilter<Call<T>> for OracleProxy {
+		fn filter(&self, call: &Call<T>) -> bool {
+			match self {
+				OracleProxy::Any => true,
+				OracleProxy::Push => matches!(
+					call,
+					Call::push_data { .. } |
+						Call::push_data_fast { .. } |
+						Call::push_data_with_confidence { .. } |
+						Call::push_entry { .. } |
+						Call::push_data_timelocked { .. } |
+						Call::push_data_observed { .. } |
+						Call::push_data_as { .. } |
+						Call::announce_push { .. } |
+						Call::execute_push { .. }
+				),
+			}
+		}
+
+		fn is_superset(&self, other: &Self) -> bool {
+			match (self, other) {
+				(x, y) if x == y => true,
+				(OracleProxy::Any, _) => true,
+				(OracleProxy::Push, _) => false,
+			}
+		}
+	}
+}
+
+// Module with decoders of reports of external oracles
+pub mod decoder {
+	use codec::DecodeAll;
+	use frame_support::pallet_prelude::{Encode, Get};
+	use sp_std::marker::PhantomData;
+
+	use super::oracle_data::Data;
+
+	/// Decoders of entries of every schema version still in storage
+	///
+	/// Entries pushed by [`push_entry`](super::Pallet::push_entry) are prefixed with the
+	/// schema version byte, so the entry format can evolve while older entries
+	/// are still decoded into the current one.
+	pub trait EntryDecoder<Entry> {
+		/// Decode `body` of an entry of the `version` schema
+		///
+		/// Returns `None` for unknown versions & malformed entries
+		fn decode(version: u8, body: &[u8]) -> Option<Entry>;
+	}
+
+	/// Entries of the `Version` schema only, SCALE encoded
+	pub struct SingleVersion<Version>(PhantomData<Version>);
+
+	impl<Entry: DecodeAll, Version: Get<u8>> EntryDecoder<Entry> for SingleVersion<Version> {
+		fn decode(version: u8, body: &[u8]) -> Option<Entry> {
+			if version != Version::get() {
+				return None
+			}
+			Entry::decode_all(&mut &body[..]).ok()
+		}
+	}
+
+	/// Format of external oracle reports
+	///
+	/// Turns reports relayed by [`submit_vaa`](super::Pallet::submit_vaa) &
+	/// [`relay_band_result`](super::Pallet::relay_band_result) into payloads to store,
+	/// so new formats are supported by implementing it rather than changing the pallet.
+	pub trait ReportDecoder {
+		/// Payload of `report`, numeric values are in units with `decimals` decimal places
+		///
+		/// Returns `None` if the report can't be decoded
+		fn decode(report: &[u8], decimals: u8) -> Option<Data>;
+	}
+
+	/// Reports stored as is
+	pub struct RawReport;
+
+	impl ReportDecoder for RawReport {
+		fn decode(report: &[u8], _decimals: u8) -> Option<Data> {
+			Some(report.to_vec())
+		}
+	}
+
+	/// Chainlink OCR reports, stored as a numeric payload of the median observation
+	///
+	/// The report is ABI encoded `(bytes32 context, bytes32 observers, int192[] observations)`
+	/// with sorted observations already in units of the feed.
+	pub struct OcrReport;
+
+	impl ReportDecoder for OcrReport {
+		fn decode(report: &[u8], _decimals: u8) -> Option<Data> {
+			const WORD: usize = 32;
+			let word = |index: usize| report.get(index * WORD..(index + 1) * WORD);
+			// Big-endian word as `u128`, if it's non-negative & fits
+			let value = |word: &[u8]| {
+				let (high, low) = word.split_at(WORD - 16);
+				high.iter().all(|byte| *byte == 0).then(|| {
+					let mut value = [0; 16];
+					value.copy_from_slice(low);
+					u128::from_be_bytes(value)
+				})
+			};
+
+			let offset = usize::try_from(value(word(2)?)?).ok()?;
+			if offset % WORD != 0 {
+				return None
+			}
+			let start = offset / WORD;
+			let len = usize::try_from(value(word(start)?)?).ok()?;
+			if len == 0 {
+				return None
+			}
+
+			Some(value(word(start.checked_add(1 + len / 2)?)?)?.encode())
+		}
+	}
+
+	/// Pyth price feed messages, stored as a numeric payload of the price
+	///
+	/// The message is a zero message type, feed id, big-endian `i64` price,
+	/// `u64` confidence & `i32` exponent, followed by fields which are ignored.
+	pub struct PythReport;
+
+	impl ReportDecoder for PythReport {
+		fn decode(report: &[u8], decimals: u8) -> Option<Data> {
+			const PRICE: usize = 1 + 32;
+			const EXPONENT: usize = PRICE + 8 + 8;
+			if report.first() != Some(&0) {
+				return None
+			}
+
+			let price = i64::from_be_bytes(report.get(PRICE..PRICE + 8)?.try_into().ok()?);
+			let exponent = i32::from_be_bytes(report.get(EXPONENT..EXPONENT + 4)?.try_into().ok()?);
+			let price = u128::try_from(price).ok()?;
+			// Price is `price * 10^exponent`, so in units it's `price * 10^(exponent + decimals)`
+			let scale = exponent.checked_add(decimals.into())?;
+			let units = if scale >= 0 {
+				price.checked_mul(10u128.checked_pow(scale.unsigned_abs())?)?
+			} else {
+				10u128.checked_pow(scale.unsigned_abs()).map_or(0, |divisor| price / divisor)
+			};
+
+			Some(units.encode())
+		}
+	}
 
 	#[cfg(test)]
-	mod oracle_data_test {
-		use super::OracleData;
-		use sp_core::ConstU64;
+	mod decoder_test {
+		use super::{OcrReport, PythReport, ReportDecoder};
+		use codec::Encode;
 
-		type OracleStorage = super::OracleStorage<u64>;
+		fn word(value: u128) -> [u8; 32] {
+			let mut word = [0; 32];
+			word[16..].copy_from_slice(&value.to_be_bytes());
+			word
+		}
 
 		#[test]
-		fn test_normal_push() {
-			let mut storage = OracleStorage::default();
-			storage.push::<ConstU64<10>>(0, b"0".to_vec()).unwrap();
-			storage.push::<ConstU64<10>>(1, b"1".to_vec()).unwrap();
-			storage.push::<ConstU64<10>>(2, b"2".to_vec()).unwrap();
+		fn test_ocr_report() {
+			let mut report = [[1; 32], [2; 32], word(0x60), word(3)].concat();
+			for observation in [100, 200, 300] {
+				report.extend(word(observation));
+			}
+			assert_eq!(OcrReport::decode(&report, 8), Some(200u128.encode()));
+
+			// Negative median
+			let negative = report.len() - 2 * 32;
+			report[negative] = 0xff;
+			assert_eq!(OcrReport::decode(&report, 8), None);
 
+			assert_eq!(OcrReport::decode(&report[..4 * 32], 8), None);
 			assert_eq!(
-				storage.0.as_slice(),
-				[
-					OracleData { saved_at: 0, data: b"0".to_vec() },
-					OracleData { saved_at: 1, data: b"1".to_vec() },
-					OracleData { saved_at: 2, data: b"2".to_vec() }
-				]
+				OcrReport::decode(&[[1; 32], [2; 32], word(0x60), word(0)].concat(), 8),
+				None
 			);
 		}
 
 		#[test]
-		fn test_failed_insert() {
-			let mut storage = OracleStorage::default();
-			storage.push::<ConstU64<10>>(10, b"0".to_vec()).unwrap();
+		fn test_pyth_report() {
+			let message = |price: i64, exponent: i32| {
+				[
+					&[0][..],
+					&[7; 32],
+					&price.to_be_bytes(),
+					&5u64.to_be_bytes(),
+					&exponent.to_be_bytes(),
+				]
+				.concat()
+			};
+
 			assert_eq!(
-				storage.push::<ConstU64<10>>(0, b"1".to_vec()).unwrap_err(),
-				super::Error::AttemptToInsertHistoricalData
+				PythReport::decode(&message(12_345, -2), 8),
+				Some(12_345_000_000u128.encode())
 			);
+			assert_eq!(PythReport::decode(&message(12_345, -4), 2), Some(123u128.encode()));
+			assert_eq!(PythReport::decode(&message(12, 1), 0), Some(120u128.encode()));
+			assert_eq!(PythReport::decode(&message(-1, 0), 0), None);
+			assert_eq!(PythReport::decode(&message(12, 0)[1..], 0), None);
 		}
+	}
+}
 
-		#[test]
-		fn test_lifetime() {
-			let mut storage = OracleStorage::default();
-			storage.push::<ConstU64<10>>(0, b"0".to_vec()).unwrap();
-			storage.push::<ConstU64<10>>(10, b"10".to_vec()).unwrap();
-			assert_eq!(storage.0.as_slice(), [OracleData { saved_at: 10, data: b"10".to_vec() }]);
+// Module with runtime policy hooks around pushes
+pub mod hooks {
+	use super::oracle_data::Data;
+
+	/// Policy applied to pushed data before it's stored or embargoed
+	///
+	/// Lets runtimes restrict pushes, e.g. to business hours, or normalize payloads
+	/// without changing the pallet.
+	pub trait PrePushFilter<AccountId, Moment> {
+		/// Data to store for `data` pushed by `who` at `now`
+		///
+		/// Returns `None` to reject the push
+		fn filter(who: &AccountId, data: Data, now: &Moment) -> Option<Data>;
+	}
 
-			storage.push::<ConstU64<10>>(100, b"100".to_vec()).unwrap();
-			assert_eq!(storage.0.as_slice(), [OracleData { saved_at: 100, data: b"100".to_vec() }]);
+	/// Every push is accepted as is
+	impl<AccountId, Moment> PrePushFilter<AccountId, Moment> for () {
+		fn filter(_who: &AccountId, data: Data, _now: &Moment) -> Option<Data> {
+			Some(data)
 		}
 	}
+
+	/// Observer of accepted pushes
+	pub trait PostPushHook<AccountId, Moment> {
+		/// Called once `data` pushed by `who` at `now` is stored or embargoed
+		fn on_push(who: &AccountId, data: &[u8], now: &Moment);
+	}
+
+	impl<AccountId, Moment> PostPushHook<AccountId, Moment> for () {
+		fn on_push(_who: &AccountId, _data: &[u8], _now: &Moment) {}
+	}
 }
 
-pub mod weights {
-	use frame_support::weights::Weight;
+/// Helpers for unit tests of pallets consuming the oracle, enabled by the `test-utils` feature
+///
+/// Lets mocks use the oracle without wiring the whole pallet, with [`MockPriceProvider`],
+/// or fill a wired pallet without authority pushes, with [`seed_events_storage`].
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils {
+	use codec::{Decode, Encode};
+	use frame_support::traits::Get;
+	use sp_std::{cell::RefCell, collections::btree_map::BTreeMap, marker::PhantomData};
+
+	use super::{
+		feed::FeedId,
+		oracle_data::Data,
+		pallet::{Config, OracleEntryOf, Pallet},
+		price::Price,
+		traits::PriceProvider,
+	};
+
+	std::thread_local! {
+		static PRICES: RefCell<BTreeMap<FeedId, (Price, Vec<u8>)>> = RefCell::new(BTreeMap::new());
+	}
+
+	/// In-memory [`PriceProvider`] of the `Feed` feed
+	///
+	/// Prices are kept per thread, so tests running in parallel don't interfere.
+	pub struct MockPriceProvider<Feed>(PhantomData<Feed>);
+
+	impl<Feed: Get<FeedId>> MockPriceProvider<Feed> {
+		/// Make `price` of `age` the latest price of the feed
+		pub fn set_price<Moment: Encode>(price: Price, age: Moment) {
+			PRICES.with(|prices| prices.borrow_mut().insert(Feed::get(), (price, age.encode())));
+		}
+
+		/// Remove the latest price of the feed
+		pub fn clear() {
+			PRICES.with(|prices| prices.borrow_mut().remove(&Feed::get()));
+		}
+	}
+
+	impl<Feed: Get<FeedId>, Moment: Decode> PriceProvider<Moment> for MockPriceProvider<Feed> {
+		fn feed_id() -> FeedId {
+			Feed::get()
+		}
+
+		fn latest_price() -> Option<(Price, Moment)> {
+			let (price, age) = PRICES.with(|prices| prices.borrow().get(&Feed::get()).cloned())?;
+			Some((price, Moment::decode(&mut &age[..]).ok()?))
+		}
+	}
 
-	/// Information about pallets pub methods weight
-	pub trait WeightInfo {
-		const PUSH_WEIGHT: Weight;
-		const CLEAN_OUTDATED_DATA_WEIGHT: Weight;
+	/// Append `entries` of the moment it's saved at & data to
+	/// [`FeedEntries`](super::FeedEntries)
+	///
+	/// Panics if entries are older than the newest stored one, as pushes would be rejected.
+	pub fn seed_events_storage<T: Config>(
+		entries: impl IntoIterator<Item = (<T as pallet_timestamp::Config>::Moment, Data)>,
+	) {
+		for (saved_at, data) in entries {
+			assert!(
+				Pallet::<T>::newest_entry().map_or(true, |(_, newest)| newest <= saved_at),
+				"entries are in chronological order"
+			);
+			Pallet::<T>::append_entry(&OracleEntryOf::<T>::new(data, saved_at, None));
+		}
 	}
 
-	/// Arbitrary defaults
-	impl WeightInfo for () {
-		const CLEAN_OUTDATED_DATA_WEIGHT: Weight = Weight::from_ref_time(10_000);
-		const PUSH_WEIGHT: Weight = Weight::from_ref_time(10_000);
+	/// Append numeric payloads of `prices` in units of [`Config::Decimals`]
+	/// with the moments they're saved at, see [`seed_events_storage`]
+	pub fn seed_prices<T: Config>(
+		prices: impl IntoIterator<Item = (<T as pallet_timestamp::Config>::Moment, u128)>,
+	) {
+		seed_events_storage::<T>(
+			prices.into_iter().map(|(saved_at, units)| (saved_at, units.encode())),
+		);
 	}
 }
 
-#[frame_support::pallet]
-pub mod pallet {
-	use sp_std::vec::Vec;
+/// Keys of the oracle authority, inserted into the node keystore with `author_insertKey`
+///
+/// Only the key type & its app crypto are defined here. The pallet has no off-chain
+/// worker yet, so [`pallet::Config`] isn't bound by `CreateSignedTransaction` and no
+/// `SignedPayload` is defined, sparing runtimes the signing setup nothing uses. A worker
+/// pushing from the node adds both, signing with [`AuthorityId`].
+pub mod crypto {
+	use sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		KeyTypeId, MultiSignature, MultiSigner,
+	};
 
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	/// Key type of the oracle authority keys
+	pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"orcl");
 
-	use super::{oracle_data, weights::WeightInfo};
+	app_crypto!(sr25519, KEY_TYPE);
 
-	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_timestamp::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	/// Signer of oracle transactions with a key from the keystore
+	///
+	/// Used with `frame_system::offchain::Signer` to send signed transactions
+	/// and sign `SignedPayload`s from the node
+	pub struct AuthorityId;
 
-		type DefaultOracleAuthority: Get<Self::AccountId>;
-		type OracleDataLifetime: Get<<Self as pallet_timestamp::Config>::Moment>;
-		type WeightInfo: WeightInfo;
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = Sr25519Signature;
+		type GenericPublic = Sr25519Public;
 	}
 
-	/// Storage for events that have been pushed to this oracle.
-	/// Stores events for the last hour as required.
-	#[pallet::storage]
-	pub type EventsStorage<T: Config> =
-		StorageValue<_, oracle_data::OracleStorage<<T as pallet_timestamp::Config>::Moment>>;
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for AuthorityId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = Sr25519Signature;
+		type GenericPublic = Sr25519Public;
+	}
+}
 
-	impl<T: Config> Pallet<T> {
-		/// Storage for events that have been pushed to this oracle.
-		/// Stores events for the last hour as required.
-        ///
-        /// Because there were no additional conditions on the data
-        /// access format, we give access only to the data itself
-        /// in chronological order.
-		pub fn oracle_data() -> Option<Vec<oracle_data::Data>> {
-			Some(
-				<EventsStorage<T> as frame_support::storage::StorageValue<
-					oracle_data::OracleStorage<<T as pallet_timestamp::Config>::Moment>,
-				>>::get()?
-				.iter_data::<<T as Config>::OracleDataLifetime>(<pallet_timestamp::Pallet<T>>::get())
-				.map(|data| data.to_vec())
-				.collect(),
-			)
+/// Verification of Wormhole VAAs, e.g. carrying Pyth price updates
+///
+/// A VAA (Verified Action Approval) is a message of a source chain
+/// signed by a quorum of the Wormhole guardian set.
+pub mod wormhole {
+	use frame_support::pallet_prelude::{Decode, Encode, RuntimeDebug};
+	use scale_info::TypeInfo;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_io::hashing::keccak_256;
+	use sp_std::vec::Vec;
+
+	/// The only supported VAA version
+	pub const VAA_VERSION: u8 = 1;
+
+	/// Ethereum-style address of a guardian key
+	pub type GuardianAddress = [u8; 20];
+
+	/// Guardian set VAAs are signed by
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, Default, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct GuardianSet {
+		pub index: u32,
+		pub addresses: Vec<GuardianAddress>,
+	}
+
+	impl GuardianSet {
+		/// Amount of signatures required, more than two thirds of the guardians
+		pub fn quorum(&self) -> usize {
+			self.addresses.len() * 2 / 3 + 1
 		}
 	}
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		Emitted { data: oracle_data::Data },
+	/// Body of a verified VAA
+	#[derive(RuntimeDebug, Clone, PartialEq, Eq)]
+	pub struct Vaa {
+		pub timestamp: u32,
+		pub nonce: u32,
+		pub emitter_chain: u16,
+		pub emitter_address: [u8; 32],
+		pub sequence: u64,
+		pub consistency_level: u8,
+		pub payload: Vec<u8>,
 	}
 
-	#[pallet::error]
-	pub enum Error<T> {
-		WrongAuthority,
-		AttemptToInsertHistoricalData,
+	#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+	pub enum VaaError {
+		/// VAA is truncated
+		Malformed,
+		UnsupportedVersion,
+		/// VAA is signed by another guardian set
+		WrongGuardianSet,
+		/// Signature isn't made by the guardian it's attributed to,
+		/// or guardians aren't in ascending order
+		InvalidSignature,
+		/// Less than [`GuardianSet::quorum`] signatures
+		NoQuorum,
 	}
 
-	impl<T> From<oracle_data::Error> for Error<T> {
-		fn from(item: oracle_data::Error) -> Self {
-			match item {
-				oracle_data::Error::AttemptToInsertHistoricalData =>
-					Self::AttemptToInsertHistoricalData,
-			}
-		}
+	/// Digest signed by the guardians, double keccak of the VAA body
+	pub fn digest(body: &[u8]) -> [u8; 32] {
+		keccak_256(&keccak_256(body))
 	}
 
-	/// Pallet Struct
-	///
-	/// Remove storage info, because pallet storage hasn't got stable size
-	#[pallet::pallet]
-	#[pallet::without_storage_info]
-	#[pallet::generate_store(pub(super) trait Store)]
-	pub struct Pallet<T>(_);
+	/// Address of a guardian with the uncompressed `public` key
+	pub fn guardian_address(public: &[u8; 64]) -> GuardianAddress {
+		let mut address = GuardianAddress::default();
+		address.copy_from_slice(&keccak_256(public)[12..]);
+		address
+	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Clean outdated data from pallet's storage
-		///
-		/// Method call allowed for anyone
-		#[pallet::weight(<T as Config>::WeightInfo::CLEAN_OUTDATED_DATA_WEIGHT + T::DbWeight::get().reads_writes(1, 1))]
-		pub fn clean_outdated_data(_origin: OriginFor<T>) -> DispatchResult {
-			<EventsStorage<T>>::try_mutate(|storage| -> Result<(), Error<T>> {
-				storage
-					.get_or_insert_with(oracle_data::OracleStorage::default)
-					.clean_outdated_data::<<T as Config>::OracleDataLifetime>(
-					<pallet_timestamp::Pallet<T>>::get(),
-				)?;
-				Ok(())
-			})?;
+	/// Big-endian reader of VAA fields
+	struct Reader<'a>(&'a [u8]);
 
-			Ok(())
+	impl<'a> Reader<'a> {
+		fn take<const N: usize>(&mut self) -> Result<[u8; N], VaaError> {
+			let (field, rest) =
+				(self.0.len() >= N).then(|| self.0.split_at(N)).ok_or(VaaError::Malformed)?;
+			self.0 = rest;
+			field.try_into().map_err(|_| VaaError::Malformed)
 		}
+	}
 
-		/// Push oracle data
-		/// Method deposite [`Event::Emitted`] & store data to pallet storage
-		///
-		/// Method call allowed only for [`Config::DefaultOracleAuthority`]
-		#[pallet::weight(<T as Config>::WeightInfo::PUSH_WEIGHT + T::DbWeight::get().reads_writes(1, 1))]
-		pub fn push_data(origin: OriginFor<T>, data: oracle_data::Data) -> DispatchResult {
-			if ensure_signed(origin)?.eq(&<T as Config>::DefaultOracleAuthority::get()) {
-				Self::deposit_event(Event::Emitted { data: data.clone() });
+	/// Parse `vaa` and check it's signed by a quorum of `guardians`
+	pub fn verify(vaa: &[u8], guardians: &GuardianSet) -> Result<Vaa, VaaError> {
+		let mut reader = Reader(vaa);
+		if reader.take::<1>()?[0] != VAA_VERSION {
+			return Err(VaaError::UnsupportedVersion)
+		}
+		if u32::from_be_bytes(reader.take()?) != guardians.index {
+			return Err(VaaError::WrongGuardianSet)
+		}
+		let signatures = reader.take::<1>()?[0] as usize;
+		if signatures < guardians.quorum() {
+			return Err(VaaError::NoQuorum)
+		}
+		let mut signed = Vec::with_capacity(signatures);
+		for _ in 0..signatures {
+			signed.push((reader.take::<1>()?[0], reader.take::<65>()?));
+		}
 
-				<EventsStorage<T>>::try_mutate(|storage| -> Result<(), Error<T>> {
-					storage
-						.get_or_insert_with(oracle_data::OracleStorage::default)
-						.push::<<T as Config>::OracleDataLifetime>(
-						<pallet_timestamp::Pallet<T>>::get(),
-						data,
-					)?;
-					Ok(())
-				})?;
+		let body = reader.0;
+		let digest = digest(body);
+		let mut previous = None;
+		for (index, signature) in signed {
+			// Ascending order rules out signatures of the same guardian
+			if previous.map_or(false, |previous| index <= previous) {
+				return Err(VaaError::InvalidSignature)
+			}
+			previous = Some(index);
 
-				Ok(())
-			} else {
-				Err(Error::<T>::WrongAuthority.into())
+			let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest)
+				.map_err(|_| VaaError::InvalidSignature)?;
+			if guardians.addresses.get(index as usize) != Some(&guardian_address(&public)) {
+				return Err(VaaError::InvalidSignature)
 			}
 		}
+
+		Ok(Vaa {
+			timestamp: u32::from_be_bytes(reader.take()?),
+			nonce: u32::from_be_bytes(reader.take()?),
+			emitter_chain: u16::from_be_bytes(reader.take()?),
+			emitter_address: reader.take()?,
+			sequence: u64::from_be_bytes(reader.take()?),
+			consistency_level: reader.take::<1>()?[0],
+			payload: reader.0.to_vec(),
+		})
+	}
+}
+
+/// Merkle Mountain Range of stored entries
+///
+/// Lets light clients & bridges verify an entry against [`pallet::Pallet::mmr_root`]
+/// with a compact proof, even after the entry is pruned. A node of `height` with `index`
+/// covers leaves `[index << height, (index + 1) << height)`. Nodes are kept off-chain,
+/// see [`node_key`], only peaks are stored on-chain.
+pub mod mmr {
+	use frame_support::pallet_prelude::{Decode, Encode, RuntimeDebug};
+	use scale_info::TypeInfo;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_std::vec::Vec;
+
+	/// Prefix of off-chain indexed MMR nodes
+	pub const NODE_PREFIX: &[u8] = b"simple-oracle::mmr";
+
+	/// Off-chain index key of the node of `height` with `index`
+	pub fn node_key(height: u32, index: u64) -> Vec<u8> {
+		(NODE_PREFIX, height, index).encode()
+	}
+
+	/// Proof of the leaf with `leaf_index` in an MMR of `leaf_count` leaves
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct Proof<Hash> {
+		pub leaf_index: u64,
+		pub leaf_count: u64,
+		/// Siblings on the path from the leaf to its peak, lowest first
+		pub siblings: Vec<Hash>,
+		/// Peaks of other mountains, highest first
+		pub peaks: Vec<Hash>,
+	}
+
+	/// Position of the mountain holding `leaf_index` among peaks of an MMR
+	/// of `leaf_count` leaves, with its height
+	fn mountain_of(leaf_index: u64, leaf_count: u64) -> Option<(usize, u32)> {
+		let mut start = 0u64;
+		mountains(leaf_count).enumerate().find_map(|(position, height)| {
+			start += 1 << height;
+			(leaf_index < start).then_some((position, height))
+		})
+	}
+
+	/// Heights of mountains of an MMR of `leaf_count` leaves, highest first
+	fn mountains(leaf_count: u64) -> impl Iterator<Item = u32> {
+		(0..u64::BITS).rev().filter(move |height| (leaf_count >> height) & 1 == 1)
+	}
+
+	/// Append `leaf` to an MMR of `leaf_count` leaves with `peaks`
+	///
+	/// `on_node` is called with the height, index & hash of each new node,
+	/// the leaf included.
+	pub fn append<Hash: Clone>(
+		peaks: &mut Vec<Hash>,
+		leaf_count: u64,
+		leaf: Hash,
+		merge: impl Fn(&Hash, &Hash) -> Hash,
+		mut on_node: impl FnMut(u32, u64, &Hash),
+	) {
+		let (mut node, mut height, mut index) = (leaf, 0, leaf_count);
+		on_node(height, index, &node);
+
+		// Each trailing one of `leaf_count` is a mountain of the same height to merge with
+		while (leaf_count >> height) & 1 == 1 {
+			let left = match peaks.pop() {
+				Some(left) => left,
+				None => break,
+			};
+			node = merge(&left, &node);
+			height += 1;
+			index >>= 1;
+			on_node(height, index, &node);
+		}
+
+		peaks.push(node);
+	}
+
+	/// Amount of nodes [`append`] adds while an MMR of `from` leaves grows to `to` leaves
+	///
+	/// Each leaf adds itself & a node per merged mountain.
+	pub fn new_nodes(from: u64, to: u64) -> u64 {
+		(from..to).map(|leaf_count| u64::from(leaf_count.trailing_ones()) + 1).sum()
+	}
+
+	/// Max amount of nodes appending `leaves` leaves to any MMR adds, see [`new_nodes`]
+	pub fn max_new_nodes(leaves: u64) -> u64 {
+		// Mountains of `height` merge once per `2^(height + 1)` leaves, so less than once
+		// per leaf in total, plus once per height at the start of the leaves
+		leaves.saturating_mul(2).saturating_add(u64::BITS.into())
+	}
+
+	/// Root of an MMR with `peaks`, peaks are bagged from the right
+	pub fn root<Hash: Clone>(peaks: &[Hash], merge: impl Fn(&Hash, &Hash) -> Hash) -> Option<Hash> {
+		peaks.iter().rev().cloned().reduce(|right, left| merge(&left, &right))
+	}
+
+	/// Proof of the leaf with `leaf_index` in an MMR of `leaf_count` leaves,
+	/// with nodes read by `node`
+	pub fn generate_proof<Hash>(
+		leaf_index: u64,
+		leaf_count: u64,
+		node: impl Fn(u32, u64) -> Option<Hash>,
+	) -> Option<Proof<Hash>> {
+		let (position, height) = mountain_of(leaf_index, leaf_count)?;
+		let siblings = (0..height)
+			.map(|level| node(level, (leaf_index >> level) ^ 1))
+			.collect::<Option<Vec<_>>>()?;
+
+		let mut start = 0u64;
+		let mut peaks = Vec::new();
+		for (other, height) in mountains(leaf_count).enumerate() {
+			if other != position {
+				peaks.push(node(height, start >> height)?);
+			}
+			start += 1 << height;
+		}
+
+		Some(Proof { leaf_index, leaf_count, siblings, peaks })
+	}
+
+	/// Check that `leaf` is in an MMR with `expected` root by `proof`
+	pub fn verify<Hash: Clone + PartialEq>(
+		expected: &Hash,
+		leaf: Hash,
+		proof: &Proof<Hash>,
+		merge: impl Fn(&Hash, &Hash) -> Hash,
+	) -> bool {
+		let (position, height) = match mountain_of(proof.leaf_index, proof.leaf_count) {
+			Some(mountain) => mountain,
+			None => return false,
+		};
+		if proof.siblings.len() != height as usize ||
+			proof.peaks.len() + 1 != proof.leaf_count.count_ones() as usize
+		{
+			return false
+		}
+
+		let peak = proof.siblings.iter().enumerate().fold(leaf, |node, (level, sibling)| {
+			if (proof.leaf_index >> level) & 1 == 0 {
+				merge(&node, sibling)
+			} else {
+				merge(sibling, &node)
+			}
+		});
+
+		let mut peaks = proof.peaks.clone();
+		peaks.insert(position, peak);
+		root(&peaks, merge).as_ref() == Some(expected)
+	}
+
+	#[cfg(test)]
+	mod mmr_test {
+		use super::{append, generate_proof, max_new_nodes, new_nodes, root, verify};
+		use sp_std::collections::btree_map::BTreeMap;
+
+		fn merge(left: &u64, right: &u64) -> u64 {
+			left.wrapping_mul(31).wrapping_add(*right).wrapping_mul(17)
+		}
+
+		#[test]
+		fn test_proofs() {
+			let mut peaks = Vec::new();
+			let mut nodes = BTreeMap::new();
+			for leaf in 0..11 {
+				append(&mut peaks, leaf, leaf + 100, merge, |height, index, node| {
+					nodes.insert((height, index), *node);
+				});
+			}
+
+			// 11 leaves make mountains of 8, 2 & 1 leaves
+			assert_eq!(peaks, [nodes[&(3, 0)], nodes[&(1, 4)], nodes[&(0, 10)]]);
+			let root = root(&peaks, merge).unwrap();
+			assert_eq!(root, merge(&peaks[0], &merge(&peaks[1], &peaks[2])));
+
+			for leaf in 0..11 {
+				let proof =
+					generate_proof(leaf, 11, |height, index| nodes.get(&(height, index)).copied())
+						.unwrap();
+				assert!(verify(&root, leaf + 100, &proof, merge));
+				assert!(!verify(&root, leaf + 101, &proof, merge));
+			}
+
+			assert_eq!(generate_proof(11, 11, |_, _| Some(0)), None);
+		}
+
+		#[test]
+		fn test_new_nodes() {
+			let mut peaks = Vec::new();
+			let mut added = Vec::new();
+			for leaf in 0..64 {
+				let mut nodes = 0;
+				append(&mut peaks, leaf, leaf, merge, |_, _, _| nodes += 1);
+				added.push(nodes);
+			}
+
+			for from in 0..64 {
+				for to in from..64 {
+					let nodes = added[from as usize..to as usize].iter().sum::<u64>();
+					assert_eq!(new_nodes(from, to), nodes);
+					assert!(nodes <= max_new_nodes(to - from));
+				}
+			}
+		}
+	}
+}
+
+/// Migrations of the pallet storage
+pub mod migrations {
+	use frame_support::log;
+
+	const LOG_TARGET: &str = "runtime::simple-oracle";
+
+	/// Adds confidence to stored & embargoed entries
+	pub mod v1 {
+		use frame_support::{
+			pallet_prelude::*,
+			traits::{GetStorageVersion, OnRuntimeUpgrade},
+		};
+		use sp_std::vec::Vec;
+
+		use super::{log, LOG_TARGET};
+		use crate::{
+			oracle_data::Data,
+			pallet::{Config, Embargoed, EmbargoedData, OracleStorageOf, Pallet},
+		};
+
+		type Moment<T> = <T as pallet_timestamp::Config>::Moment;
+
+		/// Stored data of the single encoded layout, moved to
+		/// [`FeedEntries`](crate::FeedEntries) by [`v2::MigrateToV2`](super::v2::MigrateToV2)
+		#[frame_support::storage_alias]
+		pub type EventsStorage<T: Config> = StorageValue<Pallet<T>, OracleStorageOf<T>>;
+
+		/// Entries of [`EventsStorage`] & [`EmbargoedData`] before the migration,
+		/// without confidence
+		type OldEntry<T> = (Data, Moment<T>);
+		type OldEmbargoed<T> = (Moment<T>, Moment<T>, Data);
+
+		pub struct MigrateToV1<T>(PhantomData<T>);
+
+		impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+			fn on_runtime_upgrade() -> Weight {
+				if Pallet::<T>::on_chain_storage_version() >= 1 {
+					return T::DbWeight::get().reads(1)
+				}
+
+				// Entries are re-encoded as is, so out-of-order storage stays detectable
+				let stored = convert(&<EventsStorage<T>>::hashed_key(), |old: Vec<OldEntry<T>>| {
+					let entries = old
+						.into_iter()
+						.map(|(data, saved_at)| (data, saved_at, None::<u128>))
+						.collect::<Vec<_>>();
+					OracleStorageOf::<T>::decode(&mut &entries.encode()[..]).ok()
+				});
+				let embargoed =
+					convert(&<EmbargoedData<T>>::hashed_key(), |old: Vec<OldEmbargoed<T>>| {
+						Some(
+							old.into_iter()
+								.map(|(release_at, saved_at, data)| Embargoed {
+									release_at,
+									saved_at,
+									data,
+									confidence: None,
+								})
+								.collect::<Vec<_>>(),
+						)
+					});
+
+				// Left in place & unversioned, so it can still be recovered
+				let (stored, embargoed) = match (stored, embargoed) {
+					(Some(stored), Some(embargoed)) => (stored, embargoed),
+					_ => {
+						log::error!(target: LOG_TARGET, "MigrateToV1: data isn't decodable");
+						return T::DbWeight::get().reads(3)
+					},
+				};
+				if let Some(storage) = stored {
+					<EventsStorage<T>>::put(storage);
+				}
+				if let Some(embargoed) = embargoed {
+					<EmbargoedData<T>>::put(embargoed);
+				}
+				StorageVersion::new(1).put::<Pallet<T>>();
+
+				T::DbWeight::get().reads_writes(3, 3)
+			}
+
+			/// Amounts of stored & embargoed entries, none if already migrated
+			#[cfg(feature = "try-runtime")]
+			fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+				let counts = (Pallet::<T>::on_chain_storage_version() < 1).then(|| {
+					let embargoed = <EmbargoedData<T>>::decode_len().unwrap_or_default();
+					(legacy_entries::<T>(), embargoed as u32)
+				});
+				Ok(counts.encode())
+			}
+
+			/// No entry is lost, including ones already moved by [`super::v2::MigrateToV2`]
+			#[cfg(feature = "try-runtime")]
+			fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+				let (stored, embargoed) = match Option::<(u32, u32)>::decode(&mut &state[..])
+					.map_err(|_| "MigrateToV1: invalid pre-upgrade state")?
+				{
+					Some(counts) => counts,
+					None => return Ok(()),
+				};
+
+				ensure!(
+					legacy_entries::<T>() + Pallet::<T>::stored_entries() == stored,
+					"MigrateToV1: stored entries lost"
+				);
+				ensure!(
+					<EmbargoedData<T>>::get().len() == embargoed as usize,
+					"MigrateToV1: embargoed entries lost"
+				);
+				Ok(())
+			}
+		}
+
+		/// Value under `key` decoded as `Old` & converted by `f`, `Some(None)` if it's missing
+		///
+		/// None if the value can't be decoded or converted, so nothing is written then.
+		fn convert<Old: Decode, New>(
+			key: &[u8],
+			f: impl FnOnce(Old) -> Option<New>,
+		) -> Option<Option<New>> {
+			match frame_support::storage::unhashed::get_raw(key) {
+				Some(raw) => Old::decode(&mut &raw[..]).ok().and_then(f).map(Some),
+				None => Some(None),
+			}
+		}
+
+		/// Amount of entries in [`EventsStorage`] of any layout
+		///
+		/// Both layouts are encoded as a vector, so the length prefix is read without
+		/// decoding the entries.
+		#[cfg(feature = "try-runtime")]
+		pub(super) fn legacy_entries<T: Config>() -> u32 {
+			frame_support::storage::unhashed::get_raw(&<EventsStorage<T>>::hashed_key())
+				.and_then(|raw| codec::Compact::<u32>::decode(&mut &raw[..]).ok())
+				.map_or(0, |len| len.0)
+		}
+	}
+
+	/// Moves stored data from the single encoded [`v1::EventsStorage`]
+	/// to [`FeedEntries`](crate::FeedEntries) keyed by the feed & sequence numbers
+	pub mod v2 {
+		use frame_support::{
+			pallet_prelude::*,
+			traits::{GetStorageVersion, OnRuntimeUpgrade},
+		};
+
+		#[cfg(feature = "try-runtime")]
+		use sp_std::vec::Vec;
+
+		use super::{log, v1::EventsStorage, LOG_TARGET};
+		use crate::pallet::{
+			Config, Corrupted, LatestValue, NextSequence, OracleStorageOf, Pallet, SequenceId,
+		};
+
+		pub struct MigrateToV2<T>(PhantomData<T>);
+
+		impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+			fn on_runtime_upgrade() -> Weight {
+				if Pallet::<T>::on_chain_storage_version() >= 2 {
+					return T::DbWeight::get().reads(1)
+				}
+
+				// Entries keep their sequence numbers, those stored before the first
+				// numbered one are numbered from zero
+				let mut entries = 0;
+				let moved = <EventsStorage<T>>::translate(|old: Option<OracleStorageOf<T>>| {
+					if let Some(storage) = old {
+						let len = storage.len() as SequenceId;
+						let first =
+							<NextSequence<T>>::get().map_or(0, |next| next.saturating_sub(len));
+						Pallet::<T>::put_storage(&storage, first);
+						// Out-of-order storage keeps its order until `resort_storage`
+						if storage.is_sorted() {
+							Pallet::<T>::cache_latest(&storage);
+							<Corrupted<T>>::kill();
+						} else {
+							<LatestValue<T>>::kill();
+							<Corrupted<T>>::put(true);
+						}
+						entries = len;
+					}
+					None
+				});
+				if moved.is_err() {
+					// Left in place, so it can still be recovered
+					log::error!(target: LOG_TARGET, "MigrateToV2: stored data isn't decodable");
+				}
+				StorageVersion::new(2).put::<Pallet<T>>();
+
+				T::DbWeight::get().reads_writes(3, 6 + entries)
+			}
+
+			/// Amount of entries to move, none if already migrated
+			#[cfg(feature = "try-runtime")]
+			fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+				let entries = (Pallet::<T>::on_chain_storage_version() < 2)
+					.then(super::v1::legacy_entries::<T>);
+				Ok(entries.encode())
+			}
+
+			/// All entries are moved to [`FeedEntries`](crate::FeedEntries)
+			#[cfg(feature = "try-runtime")]
+			fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+				let entries = match Option::<u32>::decode(&mut &state[..])
+					.map_err(|_| "MigrateToV2: invalid pre-upgrade state")?
+				{
+					Some(entries) => entries,
+					None => return Ok(()),
+				};
+
+				ensure!(!<EventsStorage<T>>::exists(), "MigrateToV2: stored data left in place");
+				ensure!(
+					Pallet::<T>::stored_entries() == entries,
+					"MigrateToV2: stored entries lost"
+				);
+				ensure!(
+					Pallet::<T>::on_chain_storage_version() == 2,
+					"MigrateToV2: storage version isn't updated"
+				);
+				Ok(())
+			}
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
+
+	use codec::DecodeAll;
+	use frame_support::{pallet_prelude::*, traits::Contains};
+	use frame_system::pallet_prelude::*;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_runtime::{
+		traits::{AtLeast32BitUnsigned, Hash, Saturating, Zero},
+		Permill, SaturatedConversion,
+	};
+
+	use super::{
+		audit::RejectedPush,
+		decoder::{EntryDecoder, ReportDecoder},
+		feed::FeedId,
+		hooks::{PostPushHook, PrePushFilter},
+		mmr, oracle_data,
+		price::Price,
+		traits::PriceProvider,
+		weights::WeightInfo,
+		wormhole,
+	};
+
+	/// Moments are bounded as unsigned, as [`oracle_data::OracleStorage`] requires,
+	/// while `pallet_timestamp` guarantees only `AtLeast32Bit`
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_timestamp::Config<Moment: AtLeast32BitUnsigned>
+	{
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Authority until [`Pallet::set_authority`] is called
+		type DefaultOracleAuthority: Get<Self::AccountId>;
+		/// Authority changes take effect at the next multiple of `AuthorityPeriod` blocks,
+		/// zero to apply them right away
+		///
+		/// Keeps in-flight submissions of the current authority valid until the boundary,
+		/// see [`ScheduledAuthority`]
+		type AuthorityPeriod: Get<Self::BlockNumber>;
+		/// Asset pair published by this oracle
+		#[pallet::constant]
+		type Feed: Get<FeedId>;
+		/// Lifetime until [`Pallet::set_lifetime`] is called
+		type OracleDataLifetime: Get<<Self as pallet_timestamp::Config>::Moment>;
+		/// How long data is kept in storage after it becomes unreadable
+		///
+		/// Gives dispute & audit processes time to inspect expired data,
+		/// see [`Pallet::retained_data`]
+		type PruneDelay: Get<<Self as pallet_timestamp::Config>::Moment>;
+		/// How long data pruned from [`FeedEntries`] is kept in [`Archive`], zero to delete
+		/// it right away
+		///
+		/// [`FeedEntries`] is the hot tier, served by [`Pallet::oracle_data`] & alike, while
+		/// the archive is retained for audits & read with [`Pallet::archived_at`]
+		type ArchiveWindow: Get<<Self as pallet_timestamp::Config>::Moment>;
+		/// Amount of blocks between [`Archive`] prunes, zero to never prune it
+		///
+		/// The archive is pruned independently of [`FeedEntries`], see [`Hooks::on_idle`]
+		type ArchivePruneInterval: Get<Self::BlockNumber>;
+		/// Max amount of moments in [`Archive`]
+		///
+		/// Once it's reached, data of the oldest moment is deleted to archive a newer one
+		type MaxArchiveDepth: Get<u32>;
+		/// Max total payload bytes of [`Archive`]
+		///
+		/// Data of the oldest moments over it is pruned together with the expired data,
+		/// see [`Config::ArchivePruneInterval`]
+		type MaxArchiveBytes: Get<u32>;
+		/// How long pushed data stays unreadable, zero to disable
+		///
+		/// Embargoed data is kept in [`EmbargoedData`] and released
+		/// with [`Event::DataReleased`], see [`Hooks::on_initialize`]
+		type VisibilityDelay: Get<<Self as pallet_timestamp::Config>::Moment>;
+		/// How far ahead of the push [`Pallet::push_data_timelocked`] may release data
+		type MaxReleaseDelay: Get<<Self as pallet_timestamp::Config>::Moment>;
+		/// How pushes of a payload equal to an alive entry's are treated
+		///
+		/// Guards against double submissions by redundant feeders. Duplicates are looked up
+		/// by [`HashIndex`], which costs an extra read of [`FeedEntries`] unless accepted.
+		type OnDuplicate: Get<DuplicatePolicy>;
+		/// Whether [`Event::Emitted`] is skipped for data equal to the newest stored one
+		///
+		/// Reduces event noise of feeds pushing identical readings every interval
+		type SuppressUnchanged: Get<bool>;
+		/// Deposit [`Event::EmittedSummary`] with the latest data every `EmitEvery` pushes
+		/// instead of [`Event::Emitted`] on each push, zero or one to disable
+		///
+		/// For high-frequency feeds, where per-push events overwhelm indexers
+		type EmitEvery: Get<u32>;
+		/// Whether pushed data is only stored, without [`Event::Emitted`],
+		/// [`Event::EmittedSummary`] or [`Event::DataReleased`]
+		///
+		/// For high-frequency internal feeds, whose consumers read the storage
+		/// via [`traits::PriceProvider`], so events are pure overhead
+		type StorageOnly: Get<bool>;
+		/// Decimal places of numeric payloads, see [`Price`]
+		#[pallet::constant]
+		type Decimals: Get<u8>;
+		/// Structured payload, e.g. price, volume & source count, stored SCALE encoded
+		///
+		/// See [`Pallet::push_entry`] & [`Pallet::oracle_entries`]
+		type Entry: Parameter;
+		/// Schema version of entries pushed by [`Pallet::push_entry`]
+		#[pallet::constant]
+		type EntryVersion: Get<u8>;
+		/// Decoders of entries of the current & older schema versions
+		type EntryDecoder: EntryDecoder<Self::Entry>;
+		/// Policy vetoing or transforming pushed data, see [`Error::PushFiltered`]
+		type PrePushFilter: PrePushFilter<
+			Self::AccountId,
+			<Self as pallet_timestamp::Config>::Moment,
+		>;
+		/// Observer of accepted pushes, e.g. for analytics
+		type PostPushHook: PostPushHook<Self::AccountId, <Self as pallet_timestamp::Config>::Moment>;
+		/// Max amount of entries returned by a single [`Pallet::oracle_data_page`] call
+		type MaxQueryEntries: Get<u32>;
+		/// Max amount of payload bytes returned by a single [`Pallet::oracle_data_page`] call
+		type MaxQueryBytes: Get<u32>;
+		/// How many pushes may wait for a cleanup before [`Pallet::push_data`]
+		/// cleans outdated data in place
+		///
+		/// Until then cleanup is deferred to [`Hooks::on_idle`]
+		type MaxPendingPrunes: Get<u32>;
+		/// Max size of a single push payload
+		type MaxDataLen: Get<u32>;
+		/// Max amount of entries in storage, outdated ones included until cleaned
+		type MaxEntries: Get<u32>;
+		/// Amount of the latest rejected pushes kept in [`RejectedPushes`]
+		type MaxRejectedPushes: Get<u32>;
+		/// Max amount of bytes of a snapshot chunk, see [`Pallet::export_snapshot`]
+		type SnapshotChunkLen: Get<u32>;
+		/// Wormhole guardian set accepted by [`Pallet::submit_vaa`]
+		type WormholeGuardians: Get<wormhole::GuardianSet>;
+		/// Chain id & address of the emitter of VAAs accepted by [`Pallet::submit_vaa`],
+		/// e.g. the Pyth price feeds emitter
+		type VaaEmitter: Get<(u16, [u8; 32])>;
+		/// Accounts allowed to call [`Pallet::relay_band_result`]
+		type BandRelayers: Contains<Self::AccountId>;
+		/// Least amount of validators a result relayed by [`Pallet::relay_band_result`]
+		/// has to be agreed on by
+		type MinBandAnswers: Get<u64>;
+		/// Format of reports relayed by [`Pallet::submit_vaa`] & [`Pallet::relay_band_result`]
+		type ReportDecoder: ReportDecoder;
+		/// Origin allowed to call [`Pallet::clean_outdated_data`]
+		///
+		/// Use [`EnsureAnyone`] to keep cleanup permissionless
+		type CleanOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to call [`Pallet::set_lifetime`]
+		type SetLifetimeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to call [`Pallet::set_authority`]
+		type SetAuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to call [`Pallet::set_paused`]
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to call [`Pallet::redact_entry`]
+		type RedactOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin allowed to call [`Pallet::push_data_as`]
+		type ManualPushOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		type WeightInfo: WeightInfo;
+		/// Accounts & reports the benchmarks can't make up on their own
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: super::traits::BenchmarkHelper<Self::AccountId>;
+	}
+
+	/// Storage for events that have been pushed to this oracle.
+	/// Stores events for the last hour as required.
+	///
+	/// Entries are keyed by the feed & their sequence numbers, see [`NextSequence`],
+	/// so a push writes only the key of its entry & [`EntryCount`]. Stored entries are
+	/// numbered by the last [`EntryCount`] sequence numbers, so they're read in
+	/// chronological order without sorting, see [`Pallet::load_storage`].
+	#[pallet::storage]
+	pub type FeedEntries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, FeedId, Twox64Concat, SequenceId, OracleEntryOf<T>>;
+
+	/// Amount of entries of [`Config::Feed`] in [`FeedEntries`], outdated ones included
+	///
+	/// Double maps have no counted version, so the count is kept next to the map
+	#[pallet::storage]
+	pub type EntryCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Oracle data alive for [`ReadLifetime`]
+	pub type OracleStorageOf<T> =
+		oracle_data::OracleStorage<<T as pallet_timestamp::Config>::Moment, ReadLifetime<T>>;
+
+	/// Entry of [`OracleStorageOf`]
+	pub type OracleEntryOf<T> = oracle_data::OracleData<<T as pallet_timestamp::Config>::Moment>;
+
+	/// Data pruned from [`FeedEntries`] by the moment it was saved at,
+	/// see [`Config::ArchiveWindow`]
+	#[pallet::storage]
+	pub type Archive<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		<T as pallet_timestamp::Config>::Moment,
+		Vec<oracle_data::Data>,
+		ValueQuery,
+	>;
+
+	/// Moments of [`Archive`] entries in chronological order
+	///
+	/// Lets the archive be pruned from the oldest end without iterating the map
+	#[pallet::storage]
+	pub type ArchivedMoments<T: Config> = StorageValue<
+		_,
+		BoundedVec<<T as pallet_timestamp::Config>::Moment, <T as Config>::MaxArchiveDepth>,
+		ValueQuery,
+	>;
+
+	/// Total payload bytes of [`Archive`]
+	#[pallet::storage]
+	pub type ArchivedBytes<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Amount of pushes & releases since the last cleanup, which may have left
+	/// outdated data in storage
+	#[pallet::storage]
+	pub type PendingPrunes<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The latest rejected pushes, oldest first
+	///
+	/// Filled by [`RecordRejectedPushes`](crate::RecordRejectedPushes)
+	#[pallet::storage]
+	pub type RejectedPushes<T: Config> = StorageValue<
+		_,
+		BoundedVec<
+			RejectedPush<T::AccountId, <T as pallet_timestamp::Config>::Moment>,
+			<T as Config>::MaxRejectedPushes,
+		>,
+		ValueQuery,
+	>;
+
+	/// Hash of the data allowed to be pushed by [`Pallet::execute_push`]
+	///
+	/// Dropped once the authority changes, as it was approved by the previous one
+	#[pallet::storage]
+	pub type AnnouncedPush<T: Config> = StorageValue<_, T::Hash>;
+
+	/// Sequence number of the next stored entry
+	///
+	/// Entries are removed only from the oldest end, so the `n`-th stored entry
+	/// has sequence number `NextSequence - EntryCount + n`. Unset until the first
+	/// entry is stored.
+	#[pallet::storage]
+	pub type NextSequence<T: Config> = StorageValue<_, SequenceId>;
+
+	/// Sequence number of the newest stored entry with the payload of a hash
+	///
+	/// Lets consumers holding only a payload hash locate the entry, see
+	/// [`Pallet::entry_by_hash`]. Cleaned together with outdated entries,
+	/// entries removed otherwise may leave stale items, which aren't returned.
+	#[pallet::storage]
+	pub type HashIndex<T: Config> = StorageMap<_, Identity, T::Hash, SequenceId>;
+
+	/// Peaks of the MMR of stored entries, highest first, see [`mmr`]
+	#[pallet::storage]
+	pub type MmrPeaks<T: Config> = StorageValue<_, Vec<T::Hash>, ValueQuery>;
+
+	/// Amount of leaves of the MMR of stored entries
+	#[pallet::storage]
+	pub type MmrLeaves<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Amount of pushes since the last [`Event::EmittedSummary`]
+	#[pallet::storage]
+	pub type UnreportedPushes<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Data waiting for its release, ordered by push & release moments
+	///
+	/// See [`Config::VisibilityDelay`]
+	#[pallet::storage]
+	pub type EmbargoedData<T: Config> =
+		StorageValue<_, Vec<Embargoed<<T as pallet_timestamp::Config>::Moment>>, ValueQuery>;
+
+	/// Data waiting for its release, ordered by release moments
+	///
+	/// Kept apart from [`EmbargoedData`], so it doesn't hold back later pushes, and stored
+	/// as of its release moment, see [`Pallet::push_data_timelocked`]
+	#[pallet::storage]
+	pub type TimelockedData<T: Config> =
+		StorageValue<_, Vec<Embargoed<<T as pallet_timestamp::Config>::Moment>>, ValueQuery>;
+
+	/// Pushed data hidden from reads until `release_at`
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+	pub struct Embargoed<Moment> {
+		pub release_at: Moment,
+		pub saved_at: Moment,
+		pub data: oracle_data::Data,
+		pub confidence: Option<u128>,
+	}
+
+	/// What a push stored, released & cleaned, see [`Pallet::store_weight`]
+	struct Stored {
+		/// Amount of entries stored or waiting for their release before the push
+		entries: u32,
+		/// Amount of outdated entries, if cleaned in place
+		cleaned: Option<u32>,
+		/// Amount of released entries, see [`Pallet::release`]
+		released: u32,
+		/// Amount of entries appended to [`FeedEntries`], released ones included
+		appended: u32,
+		/// Amount of MMR nodes added by the appended entries
+		mmr_nodes: u64,
+	}
+
+	/// Treatment of pushed duplicates of alive entries, see [`Config::OnDuplicate`]
+	#[derive(RuntimeDebug, Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum DuplicatePolicy {
+		/// Stored as any other data
+		Accept,
+		/// Stored, but [`Event::DuplicateSubmitted`] is deposited
+		Flag,
+		/// Rejected with [`Error::DuplicateData`]
+		Reject,
+	}
+
+	/// Estimated cost of a read, see [`Pallet::read_cost`]
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct ReadCost {
+		/// Amount of read entries
+		pub entries: u32,
+		/// Total payload bytes of read entries
+		pub bytes: u32,
+		pub weight: Weight,
+		/// Encoded size of the storage, which is read whole for any query
+		pub proof_size: u32,
+	}
+
+	/// Description of the feed, see [`Pallet::feed_metadata`]
+	///
+	/// [`Config::Entry`] type is registered in the runtime metadata with
+	/// [`Pallet::push_entry`], so front-ends can decode entries with it.
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct FeedMetadata {
+		pub feed: FeedId,
+		/// Decimal places of numeric payloads, see [`Config::Decimals`]
+		pub decimals: u8,
+		/// Schema version of new entries, see [`Config::EntryVersion`]
+		pub entry_version: u8,
+	}
+
+	/// Health metrics of the feed, see [`Pallet::health`]
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct OracleHealth<AccountId, Moment> {
+		/// Amount of stored entries, including outdated ones
+		pub entries: u32,
+		/// Moment the oldest stored entry was saved at
+		pub oldest: Option<Moment>,
+		/// Moment the newest stored entry was saved at
+		pub newest: Option<Moment>,
+		pub authority: AccountId,
+		/// Moment `authority` last pushed accepted data at
+		pub last_seen: Option<Moment>,
+		pub paused: bool,
+		/// Share of [`Config::MaxEntries`] taken by stored entries
+		pub utilization: Permill,
+	}
+
+	/// Lifetime set by [`Pallet::set_lifetime`], overrides [`Config::OracleDataLifetime`]
+	#[pallet::storage]
+	pub type Lifetime<T: Config> = StorageValue<_, <T as pallet_timestamp::Config>::Moment>;
+
+	/// Temporary lifetime with the moment it expires at, see [`Pallet::set_retention_override`]
+	#[pallet::storage]
+	pub type RetentionOverride<T: Config> = StorageValue<
+		_,
+		(<T as pallet_timestamp::Config>::Moment, <T as pallet_timestamp::Config>::Moment),
+	>;
+
+	/// Authority set by [`Pallet::set_authority`], overrides [`Config::DefaultOracleAuthority`]
+	#[pallet::storage]
+	pub type Authority<T: Config> = StorageValue<_, T::AccountId>;
+
+	/// Hash of the snapshot being imported by [`Pallet::import_snapshot`]
+	/// with its chunks received so far
+	#[pallet::storage]
+	pub type SnapshotImport<T: Config> = StorageValue<_, (T::Hash, Vec<u8>)>;
+
+	/// Id of the latest BandChain request relayed by [`Pallet::relay_band_result`]
+	#[pallet::storage]
+	pub type LastBandRequest<T: Config> = StorageValue<_, u64>;
+
+	/// Sequence number of the latest VAA accepted by [`Pallet::submit_vaa`]
+	#[pallet::storage]
+	pub type LastVaaSequence<T: Config> = StorageValue<_, u64>;
+
+	/// Moment an account last pushed accepted data at, see [`Pallet::last_seen`]
+	#[pallet::storage]
+	pub type LastSeen<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, <T as pallet_timestamp::Config>::Moment>;
+
+	/// Sequence number of the entry an account last stored, see [`Pallet::last_pushed_id`]
+	#[pallet::storage]
+	pub type LastPushedId<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, SequenceId>;
+
+	/// Authority taking effect at the block, see [`Config::AuthorityPeriod`]
+	#[pallet::storage]
+	pub type ScheduledAuthority<T: Config> = StorageValue<_, (T::BlockNumber, T::AccountId)>;
+
+	/// Account offered to become [`Pallet::authority`] by [`Pallet::transfer_feed_ownership`]
+	#[pallet::storage]
+	pub type PendingAuthority<T: Config> = StorageValue<_, T::AccountId>;
+
+	/// Whether pushes are paused by [`Pallet::set_paused`]
+	#[pallet::storage]
+	pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Whether out-of-order [`FeedEntries`] was detected,
+	/// until it's fixed by [`Pallet::resort_storage`]
+	#[pallet::storage]
+	pub type Corrupted<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Moment & payload of the newest entry of [`FeedEntries`], see [`Pallet::latest_value`]
+	///
+	/// Lets the hot read path skip decoding the whole history. Missing until the first push
+	/// after an upgrade, reads fall back to [`FeedEntries`] then.
+	#[pallet::storage]
+	pub type LatestValue<T: Config> =
+		StorageValue<_, (<T as pallet_timestamp::Config>::Moment, oracle_data::Data)>;
+
+	/// Hashes of payloads blanked by [`Pallet::redact_entry`], by sequence number
+	#[pallet::storage]
+	pub type Redacted<T: Config> = StorageMap<_, Twox64Concat, SequenceId, T::Hash>;
+
+	/// Globally increasing number of a stored entry
+	pub type SequenceId = u64;
+
+	/// Reason a push would be rejected for, see [`Pallet::would_accept`]
+	pub type RejectReason = DispatchError;
+
+	/// Lifetime of readable oracle data, see [`Pallet::lifetime`]
+	pub struct ReadLifetime<T>(PhantomData<T>);
+
+	impl<T: Config> Get<<T as pallet_timestamp::Config>::Moment> for ReadLifetime<T> {
+		fn get() -> <T as pallet_timestamp::Config>::Moment {
+			<Pallet<T>>::lifetime()
+		}
+	}
+
+	/// Origin check accepting any origin, signed or not
+	pub struct EnsureAnyone<AccountId>(PhantomData<AccountId>);
+
+	impl<O: From<frame_system::RawOrigin<AccountId>>, AccountId> EnsureOrigin<O>
+		for EnsureAnyone<AccountId>
+	{
+		type Success = ();
+
+		fn try_origin(_origin: O) -> Result<Self::Success, O> {
+			Ok(())
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn successful_origin() -> O {
+			O::from(frame_system::RawOrigin::None)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Storage for events that have been pushed to this oracle.
+		/// Stores events for the last hour as required.
+		///
+		/// Because there were no additional conditions on the data
+		/// access format, we give access only to the data itself
+		/// in chronological order.
+		pub fn oracle_data() -> Option<Vec<oracle_data::Data>> {
+			// Decoded payloads are moved out, not copied
+			Some(
+				Self::events_storage()?
+					.into_iter_data(<pallet_timestamp::Pallet<T>>::get())
+					.collect(),
+			)
+		}
+
+		/// Description of the feed & its payloads
+		pub fn feed_metadata() -> FeedMetadata {
+			FeedMetadata {
+				feed: <T as Config>::Feed::get(),
+				decimals: <T as Config>::Decimals::get(),
+				entry_version: <T as Config>::EntryVersion::get(),
+			}
+		}
+
+		/// Storage keys proving the latest value & its freshness
+		///
+		/// The runtime has no access to trie nodes, so the proof itself is fetched
+		/// for these keys with `state_getReadProof` at the same block.
+		pub fn latest_value_keys() -> Vec<Vec<u8>> {
+			vec![
+				<LatestValue<T>>::hashed_key().to_vec(),
+				<pallet_timestamp::Now<T>>::hashed_key().to_vec(),
+			]
+		}
+
+		/// Alive data decoded as [`Config::Entry`] in chronological order
+		///
+		/// Payloads which aren't entries, e.g. pushed by [`Pallet::push_data`], are skipped
+		pub fn oracle_entries() -> Option<Vec<T::Entry>> {
+			Some(
+				Self::events_storage()?
+					.iter_data(<pallet_timestamp::Pallet<T>>::get())
+					.filter_map(Self::decode_entry)
+					.collect(),
+			)
+		}
+
+		/// Alive data in chronological order, with payloads decoded as [`Config::Entry`]
+		///
+		/// Payloads which aren't entries are returned raw only
+		pub fn oracle_data_decoded() -> Option<Vec<(oracle_data::Data, Option<T::Entry>)>> {
+			Some(
+				Self::events_storage()?
+					.into_iter_data(<pallet_timestamp::Pallet<T>>::get())
+					.map(|data| {
+						let entry = Self::decode_entry(&data);
+						(data, entry)
+					})
+					.collect(),
+			)
+		}
+
+		/// Decode a payload as [`Config::Entry`] by [`Config::EntryDecoder`]
+		///
+		/// The payload is the schema version byte followed by the entry of that schema
+		pub fn decode_entry(data: &[u8]) -> Option<T::Entry> {
+			let (version, body) = data.split_first()?;
+			T::EntryDecoder::decode(*version, body)
+		}
+
+		/// Payload of `entry` of the current schema, see [`Config::EntryVersion`]
+		pub fn encode_entry(entry: &T::Entry) -> oracle_data::Data {
+			(<T as Config>::EntryVersion::get(), entry).encode()
+		}
+
+		/// Stored data, unless it's out of order
+		///
+		/// Out-of-order storage isn't read, so reads don't return wrong windows
+		/// until it's fixed by [`Pallet::resort_storage`].
+		fn events_storage() -> Option<OracleStorageOf<T>> {
+			Self::load_storage().filter(|storage| storage.is_sorted())
+		}
+
+		/// Data of [`Config::Feed`] in [`FeedEntries`], none if nothing was ever stored
+		///
+		/// Entries are read by their sequence numbers, oldest first. Once an entry
+		/// was stored, see [`NextSequence`], pruned storage is read as empty rather than missing.
+		pub(crate) fn load_storage() -> Option<OracleStorageOf<T>> {
+			let feed = <T as Config>::Feed::get();
+			let (first, next) = Self::stored_range()?;
+			Some(
+				(first..next)
+					.filter_map(|sequence| <FeedEntries<T>>::get(feed, sequence))
+					.collect(),
+			)
+		}
+
+		/// Sequence numbers of the oldest stored entry & of the next one,
+		/// none if nothing was ever stored
+		fn stored_range() -> Option<(SequenceId, SequenceId)> {
+			let next = <NextSequence<T>>::get()?;
+			Some((next.saturating_sub(<EntryCount<T>>::get().into()), next))
+		}
+
+		/// The newest stored entry, read by its key only
+		fn newest_stored() -> Option<OracleEntryOf<T>> {
+			// Entries are removed from the oldest end, so the key is gone if nothing is stored
+			<FeedEntries<T>>::get(<T as Config>::Feed::get(), Self::last_sequence()?)
+		}
+
+		/// Replace data of [`Config::Feed`] in [`FeedEntries`] with `storage`,
+		/// numbering its entries from `first`
+		///
+		/// Keys of the feed are cleared by prefix first, so keys of other numbers don't linger.
+		///
+		/// Returns the amount of cleared keys
+		pub(crate) fn put_storage(storage: &OracleStorageOf<T>, first: SequenceId) -> u32 {
+			let feed = <T as Config>::Feed::get();
+			let cleared = <FeedEntries<T>>::clear_prefix(feed, u32::MAX, None).unique;
+			for (sequence, entry) in (first..).zip(storage.iter()) {
+				<FeedEntries<T>>::insert(feed, sequence, entry);
+			}
+			<EntryCount<T>>::put(storage.len().saturated_into::<u32>());
+			<NextSequence<T>>::put(first.saturating_add(storage.len() as SequenceId));
+
+			cleared
+		}
+
+		/// Store `entry` as the newest one with the next sequence number
+		///
+		/// Writes only the key of the entry, the counters & the indexes of the entry,
+		/// see [`Pallet::index_entry`] & [`LatestValue`].
+		///
+		/// Returns the sequence number of the entry
+		pub(crate) fn append_entry(entry: &OracleEntryOf<T>) -> SequenceId {
+			let sequence = <NextSequence<T>>::get().unwrap_or_default();
+			<FeedEntries<T>>::insert(<T as Config>::Feed::get(), sequence, entry);
+			<NextSequence<T>>::put(sequence.saturating_add(1));
+			<EntryCount<T>>::mutate(|count| *count = count.saturating_add(1));
+			Self::index_entry(entry, sequence);
+			<LatestValue<T>>::put((entry.saved_at(), entry.data().to_vec()));
+
+			sequence
+		}
+
+		/// Flag out-of-order [`FeedEntries`] as [`Corrupted`]
+		///
+		/// Deposits [`Event::StorageCorrupted`] once it's detected & drops the cached
+		/// [`LatestValue`], which may not be the newest entry then. The deposit
+		/// is reverted together with a failed call, but persists from hooks.
+		fn flag_corrupted() {
+			if !<Corrupted<T>>::get() {
+				<Corrupted<T>>::put(true);
+				<LatestValue<T>>::kill();
+				Self::deposit_event(Event::StorageCorrupted);
+			}
+		}
+
+		/// All data still in storage in chronological order,
+		/// including data expired less than [`Config::PruneDelay`] ago
+		pub fn retained_data() -> Option<Vec<oracle_data::Data>> {
+			Some(
+				Self::events_storage()?
+					.iter_retained(
+						<pallet_timestamp::Pallet<T>>::get(),
+						<T as Config>::PruneDelay::get(),
+					)
+					.map(|data| data.to_vec())
+					.collect(),
+			)
+		}
+
+		/// Archived data saved exactly at `moment`, see [`Config::ArchiveWindow`]
+		pub fn archived_at(
+			moment: <T as pallet_timestamp::Config>::Moment,
+		) -> Vec<oracle_data::Data> {
+			<Archive<T>>::get(moment)
+		}
+
+		/// Moments of archived data in chronological order
+		pub fn archived_moments() -> Vec<<T as pallet_timestamp::Config>::Moment> {
+			<ArchivedMoments<T>>::get().into_inner()
+		}
+
+		/// Archived data saved in `[from, to)` in chronological order, starting at `cursor`
+		///
+		/// Bounded like [`Pallet::oracle_data_page`], pass the returned
+		/// [`oracle_data::Page::next`] back to read the rest.
+		pub fn archived_range(
+			from: <T as pallet_timestamp::Config>::Moment,
+			to: <T as pallet_timestamp::Config>::Moment,
+			cursor: Option<oracle_data::Cursor<<T as pallet_timestamp::Config>::Moment>>,
+		) -> oracle_data::Page<<T as pallet_timestamp::Config>::Moment> {
+			let (from, skip) = cursor
+				.filter(|cursor| cursor.saved_at >= from)
+				.map_or((from, 0), |cursor| (cursor.saved_at, cursor.skip as usize));
+			let max_entries = <T as Config>::MaxQueryEntries::get() as usize;
+			let max_bytes = <T as Config>::MaxQueryBytes::get() as usize;
+
+			let moments = <ArchivedMoments<T>>::get();
+			let start = moments.partition_point(|moment| *moment < from);
+			let end = moments.partition_point(|moment| *moment < to).max(start);
+
+			let mut data = Vec::new();
+			let mut bytes = 0usize;
+			for moment in &moments[start..end] {
+				// Skip only within the cursor's moment, it may have been pruned since
+				let skip = if *moment == from { skip } else { 0 };
+				for (index, archived) in
+					<Archive<T>>::get(moment).into_iter().enumerate().skip(skip)
+				{
+					bytes = bytes.saturating_add(archived.len());
+					if !data.is_empty() && (data.len() >= max_entries || bytes > max_bytes) {
+						let next =
+							oracle_data::Cursor { saved_at: *moment, skip: index.saturated_into() };
+						return oracle_data::Page { data, next: Some(next) }
+					}
+					data.push(archived);
+				}
+			}
+
+			oracle_data::Page { data, next: None }
+		}
+
+		/// Total payload bytes of archived data, see [`Config::MaxArchiveBytes`]
+		pub fn archived_bytes() -> u32 {
+			<ArchivedBytes<T>>::get()
+		}
+
+		/// Total payload bytes of `data`
+		fn total_len(data: &[oracle_data::Data]) -> u32 {
+			data.iter().map(|data| data.len()).sum::<usize>().saturated_into()
+		}
+
+		/// Alive data saved exactly at `moment`
+		pub fn oracle_data_at(
+			moment: <T as pallet_timestamp::Config>::Moment,
+		) -> Option<Vec<oracle_data::Data>> {
+			Some(
+				Self::events_storage()?
+					.data_at(<pallet_timestamp::Pallet<T>>::get(), moment)
+					.map(|data| data.to_vec())
+					.collect(),
+			)
+		}
+
+		/// Alive data saved in `[from, to)` in chronological order
+		pub fn oracle_data_range(
+			from: <T as pallet_timestamp::Config>::Moment,
+			to: <T as pallet_timestamp::Config>::Moment,
+		) -> Option<Vec<oracle_data::Data>> {
+			Some(
+				Self::events_storage()?
+					.range(<pallet_timestamp::Pallet<T>>::get(), from, to)
+					.map(|data| data.to_vec())
+					.collect(),
+			)
+		}
+
+		/// Estimated cost of reading alive data saved in `[from, to)`,
+		/// see [`Pallet::oracle_data_range`]
+		///
+		/// Lets consuming pallets budget weight of their own extrinsics
+		/// reading the oracle data.
+		pub fn read_cost(
+			from: <T as pallet_timestamp::Config>::Moment,
+			to: <T as pallet_timestamp::Config>::Moment,
+		) -> ReadCost {
+			let storage = Self::load_storage().unwrap_or_default();
+			let (entries, bytes) = storage
+				.range(<pallet_timestamp::Pallet<T>>::get(), from, to)
+				.fold((0u32, 0u32), |(entries, bytes), data| {
+					(entries.saturating_add(1), bytes.saturating_add(data.len().saturated_into()))
+				});
+
+			ReadCost {
+				entries,
+				bytes,
+				weight: <T as Config>::WeightInfo::read_data(
+					storage.len().saturated_into(),
+					entries,
+					bytes,
+				) + T::DbWeight::get().reads(2 + storage.len() as u64),
+				proof_size: storage.encoded_size().saturated_into(),
+			}
+		}
+
+		/// Check whether `data` pushed by `who` for `feed` at `moment` would be accepted
+		///
+		/// Runs every check of [`Pallet::push_data`], e.g. authority, payload size, staleness
+		/// & capacity, with all changes rolled back, so feeders can pre-flight pushes
+		/// instead of paying fees for rejected ones.
+		pub fn would_accept(
+			who: T::AccountId,
+			feed: FeedId,
+			data: oracle_data::Data,
+			moment: <T as pallet_timestamp::Config>::Moment,
+		) -> Result<(), RejectReason> {
+			ensure!(feed == <T as Config>::Feed::get(), Error::<T>::UnknownFeed);
+
+			frame_support::storage::with_transaction(|| {
+				<pallet_timestamp::Now<T>>::put(moment);
+				let result = Self::ensure_authority(frame_system::RawOrigin::Signed(who).into())
+					.and_then(|who| {
+						Self::do_push_data(&who, data, None)
+							.map(|_| ())
+							.map_err(|error| error.error)
+					});
+				frame_support::storage::TransactionOutcome::Rollback(result)
+			})
+		}
+
+		/// Max encoded size of stored data, see [`OracleStorageOf`], allowed
+		/// by [`Config::MaxEntries`] & [`Config::MaxDataLen`]
+		fn max_storage_size() -> usize {
+			let max_data_len = <T as Config>::MaxDataLen::get();
+			let max_entries = <T as Config>::MaxEntries::get();
+			// An entry is a length prefixed payload, a moment & an optional confidence
+			let max_entry_size = codec::Compact(max_data_len).encoded_size() +
+				max_data_len as usize +
+				<T as pallet_timestamp::Config>::Moment::max_encoded_len() +
+				Option::<u128>::max_encoded_len();
+
+			codec::Compact(max_entries).encoded_size() +
+				(max_entries as usize).saturating_mul(max_entry_size)
+		}
+
+		/// Log encoded sizes of stored data, [`EmbargoedData`] & [`TimelockedData`] at block `n`
+		///
+		/// Any read loads the whole data of the feed from [`FeedEntries`], so all its values
+		/// & keys are in the proof of any block reading the oracle. The size is compared
+		/// with the worst case allowed by [`Config::MaxEntries`] & [`Config::MaxDataLen`].
+		#[cfg(feature = "try-runtime")]
+		fn log_storage_size(n: BlockNumberFor<T>) {
+			let storage = Self::load_storage().unwrap_or_default();
+			let stored_size = storage.encoded_size();
+			let embargoed_size = <EmbargoedData<T>>::get().encoded_size();
+			let timelocked_size = <TimelockedData<T>>::get().encoded_size();
+			// Keys of all entries are of the same size
+			let keys_size = storage.len() *
+				<FeedEntries<T>>::hashed_key_for(
+					<T as Config>::Feed::get(),
+					SequenceId::default(),
+				)
+				.len();
+
+			let max_stored_size = Self::max_storage_size();
+
+			frame_support::log::info!(
+				target: "runtime::simple-oracle",
+				"block {:?}: {} entries, FeedEntries {} bytes of {} max ({:?} used), \
+				proof size contribution {} bytes, EmbargoedData {} bytes, TimelockedData {} bytes",
+				n,
+				storage.len(),
+				stored_size,
+				max_stored_size,
+				Permill::from_rational(stored_size as u64, max_stored_size as u64),
+				stored_size + keys_size,
+				embargoed_size,
+				timelocked_size,
+			);
+		}
+
+		/// Moment `who` last pushed accepted data at
+		pub fn last_seen(who: &T::AccountId) -> Option<<T as pallet_timestamp::Config>::Moment> {
+			<LastSeen<T>>::get(who)
+		}
+
+		/// Sequence number of the entry `who` last stored
+		///
+		/// Lets feeders reference what they stored, even if [`Event::Emitted`] isn't deposited
+		/// for the push, e.g. due to [`Config::EmitEvery`]. Embargoed data isn't stored until
+		/// its release, so it doesn't update the number.
+		pub fn last_pushed_id(who: &T::AccountId) -> Option<SequenceId> {
+			<LastPushedId<T>>::get(who)
+		}
+
+		/// Health metrics of the feed
+		///
+		/// Lets external monitoring watch the feed without raw storage queries
+		pub fn health() -> OracleHealth<T::AccountId, <T as pallet_timestamp::Config>::Moment> {
+			let storage = Self::load_storage().unwrap_or_default();
+			let entries = storage.len().saturated_into();
+			let authority = Self::authority();
+
+			OracleHealth {
+				entries,
+				oldest: storage.oldest().map(|entry| entry.saved_at()),
+				newest: storage.newest().map(|entry| entry.saved_at()),
+				last_seen: Self::last_seen(&authority),
+				authority,
+				paused: <Paused<T>>::get(),
+				utilization: Permill::from_rational(entries, <T as Config>::MaxEntries::get()),
+			}
+		}
+
+		/// Store `data` at the current moment
+		///
+		/// Only the newest entry & the counters are read, the push is counted
+		/// in [`PendingPrunes`] for [`Hooks::on_idle`] to check for outdated data.
+		/// When `allow_clean` is set and the counter reaches [`Config::MaxPendingPrunes`]
+		/// or storage is full, outdated data is cleaned in place. Data waiting for its
+		/// release counts towards [`Config::MaxEntries`].
+		///
+		/// Deposits [`Event::Emitted`] on behalf of `who`, see [`Config::SuppressUnchanged`].
+		/// If `release_at` is set, data is timelocked. If [`Config::VisibilityDelay`] is set
+		/// or there is still embargoed data, data is embargoed. Nothing is deposited
+		/// for such data until its release.
+		///
+		/// Duplicates of alive entries are treated according to [`Config::OnDuplicate`].
+		/// `data` is filtered by [`Config::PrePushFilter`] first, accepted data is passed
+		/// to [`Config::PostPushHook`].
+		///
+		/// Returns what the push stored, released & cleaned, see [`Pallet::store_weight`]
+		fn store(
+			who: &T::AccountId,
+			data: oracle_data::Data,
+			confidence: Option<u128>,
+			release_at: Option<<T as pallet_timestamp::Config>::Moment>,
+			allow_clean: bool,
+		) -> Result<Stored, Error<T>> {
+			let now = <pallet_timestamp::Pallet<T>>::get();
+			let data = <T as Config>::PrePushFilter::filter(who, data, &now)
+				.ok_or(Error::<T>::PushFiltered)?;
+			Self::ensure_data_len(&data)?;
+			// Reverted with the call if the data isn't accepted
+			<LastSeen<T>>::insert(who, now);
+			let leaves = <MmrLeaves<T>>::get();
+			// Release due data first, so entries are stored in chronological order
+			let released = Self::release(now);
+			Self::check_duplicate(who, &data)?;
+
+			let embargoed = <EmbargoedData<T>>::decode_len().unwrap_or_default();
+			let queued = embargoed
+				.saturating_add(<TimelockedData<T>>::decode_len().unwrap_or_default())
+				.saturated_into::<u32>();
+			let entries = <EntryCount<T>>::get();
+			let mut cleaned = None;
+
+			let pending = <PendingPrunes<T>>::mutate(|pending| {
+				*pending = pending.saturating_add(1);
+				*pending
+			});
+			let full = entries.saturating_add(queued) >= <T as Config>::MaxEntries::get();
+			if allow_clean &&
+				!<Corrupted<T>>::get() &&
+				(full || pending >= <T as Config>::MaxPendingPrunes::get())
+			{
+				cleaned = Some(Self::prune(now, u32::MAX)?.0);
+				<PendingPrunes<T>>::kill();
+			}
+
+			ensure!(
+				<EntryCount<T>>::get().saturating_add(queued) < <T as Config>::MaxEntries::get(),
+				Error::<T>::StorageFull
+			);
+
+			let delay = <T as Config>::VisibilityDelay::get();
+			if let Some(release_at) = release_at {
+				Self::timelock(
+					who,
+					now,
+					data,
+					confidence,
+					release_at.max(now.saturating_add(delay)),
+				);
+			} else if !delay.is_zero() || embargoed > 0 {
+				// Data can't overtake still embargoed data
+				Self::embargo(who, now, data, confidence, now.saturating_add(delay))?;
+			} else {
+				Self::save(who, now, data, confidence)?;
+			}
+
+			let (appended, mmr_nodes) = Self::appended_since(leaves);
+			Ok(Stored {
+				entries: entries.saturating_add(queued),
+				cleaned,
+				released,
+				appended,
+				mmr_nodes,
+			})
+		}
+
+		/// Append `data` pushed by `who` at `now` to [`FeedEntries`]
+		///
+		/// Fails if storage is out of order or holds newer data.
+		fn save(
+			who: &T::AccountId,
+			now: <T as pallet_timestamp::Config>::Moment,
+			data: oracle_data::Data,
+			confidence: Option<u128>,
+		) -> Result<(), Error<T>> {
+			ensure!(!<Corrupted<T>>::get(), Error::<T>::StorageCorrupted);
+			let newest = Self::newest_stored();
+			let newest = newest.as_ref();
+			ensure!(
+				newest.map_or(true, |newest| newest.saved_at() <= now),
+				Error::<T>::StaleSubmission
+			);
+			let unchanged = newest.map_or(false, |newest| newest.data() == data);
+			let entry = OracleEntryOf::<T>::new(data, now, confidence);
+			let sequence = Self::append_entry(&entry);
+			<LastPushedId<T>>::insert(who, sequence);
+			<T as Config>::PostPushHook::on_push(who, entry.data(), &now);
+
+			// Deposited only once the data is stored, so events mirror the storage
+			if !(unchanged && <T as Config>::SuppressUnchanged::get()) {
+				if let Some(event) = Self::emitted_event(sequence, entry.data(), confidence) {
+					Self::deposit_emitted(who, event);
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Put `data` pushed by `who` at `now` to [`EmbargoedData`] until `release_at`
+		///
+		/// Data is released in push order, so `release_at` is raised
+		/// to the release moment of the previously embargoed data.
+		/// Embargoed data is passed to [`Config::PostPushHook`].
+		fn embargo(
+			who: &T::AccountId,
+			now: <T as pallet_timestamp::Config>::Moment,
+			data: oracle_data::Data,
+			confidence: Option<u128>,
+			release_at: <T as pallet_timestamp::Config>::Moment,
+		) -> Result<(), Error<T>> {
+			<EmbargoedData<T>>::try_mutate(|embargoed| {
+				ensure!(
+					embargoed.last().map_or(true, |last| last.saved_at <= now),
+					Error::<T>::StaleSubmission
+				);
+				let release_at =
+					embargoed.last().map_or(release_at, |last| release_at.max(last.release_at));
+				embargoed.push(Embargoed { release_at, saved_at: now, data, confidence });
+				if let Some(last) = embargoed.last() {
+					<T as Config>::PostPushHook::on_push(who, &last.data, &now);
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Put `data` pushed by `who` at `now` to [`TimelockedData`] until `release_at`
+		///
+		/// Data is ordered by release moments, data released at the same moment
+		/// keeps push order. Timelocked data is passed to [`Config::PostPushHook`].
+		fn timelock(
+			who: &T::AccountId,
+			now: <T as pallet_timestamp::Config>::Moment,
+			data: oracle_data::Data,
+			confidence: Option<u128>,
+			release_at: <T as pallet_timestamp::Config>::Moment,
+		) {
+			<T as Config>::PostPushHook::on_push(who, &data, &now);
+			<TimelockedData<T>>::mutate(|timelocked| {
+				let index = timelocked.partition_point(|entry| entry.release_at <= release_at);
+				timelocked.insert(index, Embargoed { release_at, saved_at: now, data, confidence });
+			});
+		}
+
+		/// Move embargoed & timelocked data due at `now` to [`FeedEntries`],
+		/// depositing [`Event::DataReleased`] for each entry
+		///
+		/// Embargoed data is stored as of its push moment, timelocked data
+		/// as of its release moment, see [`TimelockedData`].
+		///
+		/// Returns the amount of released entries
+		pub(crate) fn release(now: <T as pallet_timestamp::Config>::Moment) -> u32 {
+			let mut embargoed = <EmbargoedData<T>>::get();
+			let due = embargoed.partition_point(|entry| entry.release_at <= now);
+			let mut timelocked = <TimelockedData<T>>::get();
+			let due_timelocked = timelocked.partition_point(|entry| entry.release_at <= now);
+
+			if due == 0 && due_timelocked == 0 {
+				return 0
+			}
+
+			// Data is kept until out-of-order storage is fixed
+			if <Corrupted<T>>::get() {
+				return 0
+			}
+
+			let mut released = embargoed
+				.drain(..due)
+				.map(|entry| (entry.saved_at, entry))
+				.chain(timelocked.drain(..due_timelocked).map(|entry| (entry.release_at, entry)))
+				.collect::<Vec<_>>();
+			// Stable, so data stored as of the same moment keeps push order
+			released.sort_by_key(|(stored_at, _)| *stored_at);
+
+			let mut newest = Self::newest_stored().map(|newest| newest.saved_at());
+			for (stored_at, Embargoed { data, confidence, .. }) in released {
+				// Can be older only if the data was stored directly after being embargoed,
+				// i.e. the delay was changed in between, it's stored as of the newest entry then
+				let saved_at = newest.map_or(stored_at, |newest| newest.max(stored_at));
+				newest = Some(saved_at);
+				let entry = OracleEntryOf::<T>::new(data, saved_at, confidence);
+				let sequence = Self::append_entry(&entry);
+				if !<T as Config>::StorageOnly::get() {
+					Self::deposit_event(Event::DataReleased {
+						sequence,
+						data: entry.into_data(),
+						saved_at,
+						confidence,
+					});
+				}
+			}
+
+			<PendingPrunes<T>>::mutate(|pending| *pending = pending.saturating_add(1));
+
+			if embargoed.is_empty() {
+				<EmbargoedData<T>>::kill();
+			} else {
+				<EmbargoedData<T>>::put(embargoed);
+			}
+			if timelocked.is_empty() {
+				<TimelockedData<T>>::kill();
+			} else {
+				<TimelockedData<T>>::put(timelocked);
+			}
+
+			due.saturating_add(due_timelocked) as u32
+		}
+
+		/// Apply [`Config::OnDuplicate`] if `data` equals the payload of an alive entry
+		fn check_duplicate(who: &T::AccountId, data: &[u8]) -> Result<(), Error<T>> {
+			let policy = <T as Config>::OnDuplicate::get();
+			if policy == DuplicatePolicy::Accept {
+				return Ok(())
+			}
+
+			if let Some((original, ..)) = Self::entry_by_hash(T::Hashing::hash(data)) {
+				ensure!(policy != DuplicatePolicy::Reject, Error::<T>::DuplicateData);
+				Self::deposit_event(Event::DuplicateSubmitted { who: who.clone(), original });
+			}
+
+			Ok(())
+		}
+
+		/// Replace stored data with `storage`, numbering its entries by their positions
+		///
+		/// Indexes by sequence number are rebuilt for the new entries, i.e. [`HashIndex`]
+		/// & the MMR, or cleared, i.e. [`LastPushedId`] & [`Redacted`]. Data embargoed
+		/// or timelocked before is dropped, as it may be older than the new entries.
+		///
+		/// Returns the amount of cleared keys of [`FeedEntries`]
+		fn replace_storage(storage: OracleStorageOf<T>) -> u32 {
+			let _ = <HashIndex<T>>::clear(u32::MAX, None);
+			let _ = <LastPushedId<T>>::clear(u32::MAX, None);
+			let _ = <Redacted<T>>::clear(u32::MAX, None);
+			<MmrPeaks<T>>::kill();
+			<MmrLeaves<T>>::kill();
+			for (sequence, entry) in (0..).zip(storage.iter()) {
+				Self::index_entry(entry, sequence);
+			}
+
+			Self::cache_latest(&storage);
+			let cleared = Self::put_storage(&storage, 0);
+			<EmbargoedData<T>>::kill();
+			<TimelockedData<T>>::kill();
+			<PendingPrunes<T>>::kill();
+			<Corrupted<T>>::kill();
+
+			cleared
+		}
+
+		/// Move indexes by sequence number of `entries`, listed with their previous numbers,
+		/// to the numbers counted from `first`
+		///
+		/// [`HashIndex`], [`Redacted`] & [`LastPushedId`] follow their entries, while the MMR
+		/// is rebuilt of `entries`, as by [`Pallet::replace_storage`].
+		fn renumber(entries: &[(SequenceId, OracleEntryOf<T>)], first: SequenceId) {
+			let tombstones: Vec<_> =
+				entries.iter().map(|(previous, _)| <Redacted<T>>::take(previous)).collect();
+			for (previous, entry) in entries {
+				Self::unindex(T::Hashing::hash(entry.data()), *previous);
+			}
+
+			<MmrPeaks<T>>::kill();
+			<MmrLeaves<T>>::kill();
+			let mut moved = BTreeMap::new();
+			for ((sequence, (previous, entry)), tombstone) in (first..).zip(entries).zip(tombstones)
+			{
+				Self::index_entry(entry, sequence);
+				if let Some(hash) = tombstone {
+					<Redacted<T>>::insert(sequence, hash);
+				}
+				moved.insert(*previous, sequence);
+			}
+			<LastPushedId<T>>::translate_values(|previous: SequenceId| {
+				Some(moved.get(&previous).copied().unwrap_or(previous))
+			});
+		}
+
+		/// Weight of resorting storage holding `entries` entries, see [`Pallet::renumber`]
+		///
+		/// Each entry is read & rewritten with its [`HashIndex`] & [`Redacted`] items
+		/// & appended to the rebuilt MMR. [`LastPushedId`] holds an item per pusher only,
+		/// so it isn't charged.
+		fn resort_weight(entries: u32) -> Weight {
+			let entries_count = u64::from(entries);
+			<T as Config>::WeightInfo::resort_storage(entries) +
+				T::DbWeight::get().reads_writes(
+					1 + 5 * entries_count,
+					(6 + 8 * entries_count).saturating_add(mmr::max_new_nodes(entries_count)),
+				)
+		}
+
+		/// Weight of deleting `entries` stored entries & [`LastPushedId`] items of `pushers`,
+		/// see [`Pallet::delete_feed_data`]
+		///
+		/// Each entry is read & removed with its [`HashIndex`] & [`Redacted`] items,
+		/// the queues & counters are reset once.
+		fn delete_weight(entries: u32, pushers: u32) -> Weight {
+			let entries_count = u64::from(entries);
+			<T as Config>::WeightInfo::delete_feed_data(entries) +
+				T::DbWeight::get().reads_writes(
+					2 + 2 * entries_count,
+					7 + 3 * entries_count + u64::from(pushers),
+				)
+		}
+
+		/// Index `entry` with the `sequence` number by its payload hash & append it to the MMR
+		fn index_entry(entry: &OracleEntryOf<T>, sequence: SequenceId) {
+			<HashIndex<T>>::insert(T::Hashing::hash(entry.data()), sequence);
+			Self::append_mmr_leaf(entry, sequence);
+		}
+
+		/// Cache the newest entry of `storage` in [`LatestValue`]
+		pub(crate) fn cache_latest(storage: &OracleStorageOf<T>) {
+			match storage.newest() {
+				Some(newest) => <LatestValue<T>>::put((newest.saved_at(), newest.data().to_vec())),
+				None => <LatestValue<T>>::kill(),
+			}
+		}
+
+		/// Append `entry` with the `sequence` number to the MMR
+		///
+		/// New nodes are written to the off-chain index under [`mmr::node_key`],
+		/// so nodes need offchain indexing enabled to serve proofs.
+		fn append_mmr_leaf(entry: &OracleEntryOf<T>, sequence: SequenceId) {
+			let leaf = Self::mmr_leaf(sequence, entry.data(), entry.saved_at());
+
+			let mut peaks = <MmrPeaks<T>>::get();
+			let leaf_count = <MmrLeaves<T>>::get();
+			mmr::append(&mut peaks, leaf_count, leaf, Self::mmr_merge, |height, index, node| {
+				sp_io::offchain_index::set(&mmr::node_key(height, index), &node.encode())
+			});
+			<MmrPeaks<T>>::put(peaks);
+			<MmrLeaves<T>>::put(leaf_count.saturating_add(1));
+		}
+
+		/// MMR leaf of the entry with the `sequence` number, `data` payload & saved at `saved_at`
+		pub fn mmr_leaf(
+			sequence: SequenceId,
+			data: &[u8],
+			saved_at: <T as pallet_timestamp::Config>::Moment,
+		) -> T::Hash {
+			T::Hashing::hash_of(&(sequence, data, saved_at))
+		}
+
+		/// Parent of `left` & `right` MMR nodes
+		pub fn mmr_merge(left: &T::Hash, right: &T::Hash) -> T::Hash {
+			T::Hashing::hash_of(&(left, right))
+		}
+
+		/// Root of the MMR of all entries stored so far, none before the first one
+		///
+		/// Entries are verified against it with [`mmr::verify`] & [`Pallet::mmr_merge`].
+		pub fn mmr_root() -> Option<T::Hash> {
+			mmr::root(&<MmrPeaks<T>>::get(), Self::mmr_merge)
+		}
+
+		/// Amount of leaves of the MMR, i.e. of entries stored so far
+		pub fn mmr_leaves() -> u64 {
+			<MmrLeaves<T>>::get()
+		}
+
+		/// Alive entry with the payload of `hash`, with its sequence number
+		/// & the moment it was saved at, see [`HashIndex`]
+		pub fn entry_by_hash(
+			hash: T::Hash,
+		) -> Option<(SequenceId, oracle_data::Data, <T as pallet_timestamp::Config>::Moment)> {
+			let sequence = <HashIndex<T>>::get(hash)?;
+			let (data, saved_at) = Self::entry_by_sequence(sequence)?;
+			// Stale items may point to other entries, e.g. after a redaction
+			(T::Hashing::hash(&data) == hash).then_some((sequence, data, saved_at))
+		}
+
+		/// Alive entry with the `sequence` number, with the moment it was saved at
+		///
+		/// Lets consumers reference & deduplicate specific data points
+		pub fn entry_by_sequence(
+			sequence: SequenceId,
+		) -> Option<(oracle_data::Data, <T as pallet_timestamp::Config>::Moment)> {
+			let storage = Self::events_storage()?;
+			let entry = storage
+				.alive_at(<pallet_timestamp::Pallet<T>>::get(), Self::sequence_index(sequence)?)?;
+
+			Some((entry.data().to_vec(), entry.saved_at()))
+		}
+
+		/// Confidence of the alive entry with the `sequence` number,
+		/// if it was pushed by [`Pallet::push_data_with_confidence`]
+		pub fn confidence_by_sequence(sequence: SequenceId) -> Option<u128> {
+			let storage = Self::events_storage()?;
+			storage
+				.alive_at(<pallet_timestamp::Pallet<T>>::get(), Self::sequence_index(sequence)?)?
+				.confidence()
+		}
+
+		/// Position of the entry with the `sequence` number among stored entries
+		fn sequence_index(sequence: SequenceId) -> Option<usize> {
+			sequence.checked_sub(Self::stored_range()?.0)?.try_into().ok()
+		}
+
+		/// Amount of stored entries, including outdated ones
+		pub fn stored_entries() -> u32 {
+			<EntryCount<T>>::get()
+		}
+
+		/// Whether there are no stored entries, even outdated ones
+		pub fn is_storage_empty() -> bool {
+			<EntryCount<T>>::get() == 0
+		}
+
+		/// Total payload bytes of stored entries, including outdated ones
+		pub fn stored_bytes() -> u32 {
+			Self::load_storage().map_or(0, |storage| storage.total_bytes().saturated_into())
+		}
+
+		/// The oldest stored entry with the moment it was saved at, even if it's outdated
+		pub fn oldest_entry() -> Option<(oracle_data::Data, <T as pallet_timestamp::Config>::Moment)>
+		{
+			let (first, _) = Self::stored_range()?;
+			<FeedEntries<T>>::get(<T as Config>::Feed::get(), first)
+				.map(|entry| (entry.data().to_vec(), entry.saved_at()))
+		}
+
+		/// The newest stored entry with the moment it was saved at, even if it's outdated
+		pub fn newest_entry() -> Option<(oracle_data::Data, <T as pallet_timestamp::Config>::Moment)>
+		{
+			Self::newest_stored().map(|entry| (entry.data().to_vec(), entry.saved_at()))
+		}
+
+		/// Sequence number of the newest stored entry
+		pub fn last_sequence() -> Option<SequenceId> {
+			<NextSequence<T>>::get()?.checked_sub(1)
+		}
+
+		/// Clean no more than `max_items` outdated entries
+		///
+		/// Nothing is cleaned while the clock is behind the newest entry.
+		///
+		/// Returns the amount of cleaned entries
+		fn clean(max_items: u32) -> Result<u32, Error<T>> {
+			let now = <pallet_timestamp::Pallet<T>>::get();
+			ensure!(!<Corrupted<T>>::get(), Error::<T>::StorageCorrupted);
+			ensure!(
+				Self::newest_stored().map_or(true, |newest| newest.saved_at() <= now),
+				Error::<T>::StaleSubmission
+			);
+
+			let (cleaned, done) = Self::prune(now, max_items)?;
+			if done {
+				<PendingPrunes<T>>::kill();
+			}
+
+			Ok(cleaned)
+		}
+
+		/// Weight of cleaning `cleaned` outdated entries, see [`Pallet::clean`]
+		///
+		/// Each cleaned entry is read & removed together with its [`HashIndex`] item
+		/// & archived, the entry after them, the newest one & the counters are read once.
+		fn clean_weight(cleaned: u32) -> Weight {
+			<T as Config>::WeightInfo::clean_outdated_data(cleaned) +
+				T::DbWeight::get()
+					.reads_writes(10 + 2 * u64::from(cleaned), 5 + 4 * u64::from(cleaned))
+		}
+
+		/// Storage accesses of appending `appended` entries, which add `mmr_nodes` MMR nodes,
+		/// see [`Pallet::append_entry`]
+		///
+		/// Each entry reads & writes the counters & the MMR, writes its key, [`HashIndex`]
+		/// & [`LatestValue`], new MMR nodes are written to the off-chain index.
+		fn append_weight(appended: u32, mmr_nodes: u64) -> Weight {
+			T::DbWeight::get().reads_writes(
+				4 * u64::from(appended),
+				(7 * u64::from(appended)).saturating_add(mmr_nodes),
+			)
+		}
+
+		/// Amount of entries appended since the MMR had `leaves` leaves,
+		/// with the amount of MMR nodes they added
+		fn appended_since(leaves: u64) -> (u32, u64) {
+			let now = <MmrLeaves<T>>::get();
+			(now.saturating_sub(leaves).saturated_into(), mmr::new_nodes(leaves, now))
+		}
+
+		/// Weight of releasing `released` entries, see [`Pallet::release`]
+		///
+		/// Reads of both queues are charged by the caller, as they're read anyway,
+		/// appending the released entries as well, see [`Pallet::append_weight`].
+		fn release_weight(released: u32) -> Weight {
+			if released == 0 {
+				return Weight::zero()
+			}
+
+			// The corruption flag, the newest entry & the pending prunes are read,
+			// the pending prunes & both queues are written
+			<T as Config>::WeightInfo::release_data(released) +
+				T::DbWeight::get().reads_writes(4, 3)
+		}
+
+		/// Weight of storage accesses of [`Pallet::store`] & of what it released
+		/// & cleaned, the push itself is charged by the caller
+		fn store_weight(stored: &Stored) -> Weight {
+			// Duplicates are looked up among all stored entries
+			let duplicates = if <T as Config>::OnDuplicate::get() == DuplicatePolicy::Accept {
+				Weight::zero()
+			} else {
+				T::DbWeight::get().reads(1 + u64::from(stored.entries))
+			};
+
+			// The clock, both queues, the counters, the MMR leaves, the corruption flag,
+			// the newest entry & the summary counter are read. The pusher, the pending
+			// prunes, the sequence number of the pusher & the summary counter are written,
+			// or a queue instead of the last two.
+			T::DbWeight::get().reads_writes(10, 4) +
+				duplicates + Self::append_weight(stored.appended, stored.mmr_nodes) +
+				Self::release_weight(stored.released) +
+				stored.cleaned.map(Self::clean_weight).unwrap_or_default()
+		}
+
+		/// [`Pallet::store_weight`] in the worst case, full queues released to full storage,
+		/// which is cleaned as a whole if `clean` is set
+		fn max_store_weight(clean: bool) -> Weight {
+			let max_entries = <T as Config>::MaxEntries::get();
+			let appended = max_entries.saturating_add(1);
+			Self::store_weight(&Stored {
+				entries: max_entries,
+				cleaned: clean.then_some(max_entries),
+				released: max_entries,
+				appended,
+				mmr_nodes: mmr::max_new_nodes(appended.into()),
+			})
+		}
+
+		/// Prune no more than `max_items` entries outdated for [`Config::PruneDelay`],
+		/// see [`Pallet::remove_oldest`]
+		fn prune(
+			now: <T as pallet_timestamp::Config>::Moment,
+			max_items: u32,
+		) -> Result<(u32, bool), Error<T>> {
+			let kept_for = Self::lifetime().saturating_add(<T as Config>::PruneDelay::get());
+			Self::remove_oldest(max_items, |entry| {
+				// Data saved after `now`, e.g. if the clock went backwards, isn't outdated
+				entry.saved_at() <= now && now.saturating_sub(entry.saved_at()) >= kept_for
+			})
+		}
+
+		/// Remove no more than `max_items` oldest entries while `f` holds for them,
+		/// see [`Pallet::retire`]
+		///
+		/// Entries are read oldest first, so only the removed entries & the next one are read.
+		/// Fails if they're out of order, see [`Pallet::flag_corrupted`].
+		///
+		/// Returns the amount of removed entries & whether no entries `f` holds for are left
+		fn remove_oldest(
+			max_items: u32,
+			f: impl Fn(&OracleEntryOf<T>) -> bool,
+		) -> Result<(u32, bool), Error<T>> {
+			let feed = <T as Config>::Feed::get();
+			let (first, next) = match Self::stored_range() {
+				Some(range) => range,
+				None => return Ok((0, true)),
+			};
+
+			let mut removed = Vec::<OracleEntryOf<T>>::new();
+			let mut done = true;
+			for sequence in first..next {
+				let entry = match <FeedEntries<T>>::get(feed, sequence) {
+					Some(entry) => entry,
+					None => break,
+				};
+				if removed.last().map_or(false, |last| last.saved_at() > entry.saved_at()) {
+					Self::flag_corrupted();
+					return Err(Error::<T>::StorageCorrupted)
+				}
+				if !f(&entry) {
+					break
+				}
+				if removed.len() >= max_items as usize {
+					done = false;
+					break
+				}
+				removed.push(entry);
+			}
+
+			for sequence in (first..).take(removed.len()) {
+				<FeedEntries<T>>::remove(feed, sequence);
+			}
+			<EntryCount<T>>::mutate(|count| {
+				*count = count.saturating_sub(removed.len().saturated_into())
+			});
+
+			Ok((Self::retire(first, removed), done))
+		}
+
+		/// Remove the entry with `sequence` number from [`HashIndex`],
+		/// unless `hash` of its payload already points to a newer entry
+		fn unindex(hash: T::Hash, sequence: SequenceId) {
+			if <HashIndex<T>>::get(hash) == Some(sequence) {
+				<HashIndex<T>>::remove(hash);
+			}
+		}
+
+		/// Forget the oldest entries `removed` from [`FeedEntries`], numbered from `first`
+		///
+		/// Removed entries are dropped from [`HashIndex`] & moved to [`Archive`]
+		/// if [`Config::ArchiveWindow`] is set.
+		///
+		/// Returns the amount of removed entries
+		fn retire(first: SequenceId, removed: Vec<OracleEntryOf<T>>) -> u32 {
+			let amount = removed.len().saturated_into();
+
+			for (sequence, entry) in (first..).zip(&removed) {
+				Self::unindex(T::Hashing::hash(entry.data()), sequence);
+			}
+			// Entries are removed oldest first, so the newest one is removed last
+			if <EntryCount<T>>::get() == 0 {
+				<LatestValue<T>>::kill();
+			}
+
+			if <T as Config>::ArchiveWindow::get().is_zero() {
+				return amount
+			}
+
+			let max_depth = <T as Config>::MaxArchiveDepth::get() as usize;
+			let mut bytes = <ArchivedBytes<T>>::get();
+			<ArchivedMoments<T>>::mutate(|moments| {
+				for entry in removed {
+					let saved_at = entry.saved_at();
+					// Entries are removed oldest first, so moments stay in chronological order
+					if moments.last() != Some(&saved_at) {
+						if max_depth == 0 {
+							continue
+						}
+						if moments.len() >= max_depth {
+							let removed = <Archive<T>>::take(moments.remove(0));
+							bytes = bytes.saturating_sub(Self::total_len(&removed));
+						}
+						let _ = moments.try_push(saved_at);
+					}
+					bytes = bytes.saturating_add(entry.data().len().saturated_into());
+					<Archive<T>>::append(saved_at, entry.into_data());
+				}
+			});
+			<ArchivedBytes<T>>::put(bytes);
+
+			amount
+		}
+
+		/// Remove no more than `max_items` oldest moments of [`Archive`], which are
+		/// kept for [`Config::ArchiveWindow`] after they'd been pruned already
+		/// or don't fit into [`Config::MaxArchiveBytes`]
+		///
+		/// Returns the amount of removed moments
+		pub(crate) fn prune_archive(max_items: u32) -> u32 {
+			let now = <pallet_timestamp::Pallet<T>>::get();
+			let kept_for = Self::lifetime()
+				.saturating_add(<T as Config>::PruneDelay::get())
+				.saturating_add(<T as Config>::ArchiveWindow::get());
+			let max_bytes = <T as Config>::MaxArchiveBytes::get();
+
+			let mut moments = <ArchivedMoments<T>>::get().into_inner();
+			let expired = moments.partition_point(|moment| moment.saturating_add(kept_for) <= now);
+
+			let mut bytes = <ArchivedBytes<T>>::get();
+			let mut point = 0;
+			while point < moments.len().min(max_items as usize) &&
+				(point < expired || bytes > max_bytes)
+			{
+				bytes = bytes.saturating_sub(Self::total_len(&<Archive<T>>::take(moments[point])));
+				point += 1;
+			}
+			if point == 0 {
+				return 0
+			}
+
+			moments.drain(..point);
+			// Only shrunk, so nothing is truncated
+			<ArchivedMoments<T>>::put(BoundedVec::truncate_from(moments));
+			<ArchivedBytes<T>>::put(bytes);
+
+			point as u32
+		}
+
+		/// Clean outdated data left by pushes, if `remaining_weight` is enough
+		fn idle_clean(remaining_weight: Weight) -> Weight {
+			let base_weight = Self::clean_weight(0);
+			let item_weight = Self::clean_weight(1).saturating_sub(base_weight);
+
+			if remaining_weight.ref_time() < base_weight.ref_time() {
+				return Weight::zero()
+			}
+
+			if <PendingPrunes<T>>::get() == 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let max_items = (remaining_weight.ref_time() - base_weight.ref_time())
+				.checked_div(item_weight.ref_time())
+				.unwrap_or(u64::MAX)
+				.min(u32::MAX.into()) as u32;
+
+			// Can fail only if the timestamp went backwards or storage is out of order,
+			// in that case the data is left for an explicit call
+			Self::clean_weight(Self::clean(max_items).unwrap_or_default())
+		}
+
+		/// Prune [`Archive`], if `remaining_weight` is enough
+		fn idle_prune_archive(remaining_weight: Weight) -> Weight {
+			let base_weight =
+				<T as Config>::WeightInfo::prune_archive(0) + T::DbWeight::get().reads_writes(4, 2);
+			let item_weight = (<T as Config>::WeightInfo::prune_archive(1) +
+				T::DbWeight::get().reads_writes(1, 1))
+			.saturating_sub(<T as Config>::WeightInfo::prune_archive(0));
+
+			if remaining_weight.ref_time() < base_weight.ref_time() {
+				return Weight::zero()
+			}
+
+			let max_items = (remaining_weight.ref_time() - base_weight.ref_time())
+				.checked_div(item_weight.ref_time())
+				.unwrap_or(u64::MAX)
+				.min(u32::MAX.into()) as u32;
+
+			let pruned = Self::prune_archive(max_items);
+
+			<T as Config>::WeightInfo::prune_archive(pruned) +
+				T::DbWeight::get().reads_writes(4 + u64::from(pruned), 2 + u64::from(pruned))
+		}
+
+		/// Lifetime of oracle data
+		///
+		/// [`RetentionOverride`] until it expires, then [`Lifetime`] if set,
+		/// [`Config::OracleDataLifetime`] otherwise
+		pub fn lifetime() -> <T as pallet_timestamp::Config>::Moment {
+			<RetentionOverride<T>>::get()
+				.filter(|(_, expires_at)| <pallet_timestamp::Pallet<T>>::get() < *expires_at)
+				.map(|(lifetime, _)| lifetime)
+				.or_else(<Lifetime<T>>::get)
+				.unwrap_or_else(<T as Config>::OracleDataLifetime::get)
+		}
+
+		/// Account allowed to push data
+		///
+		/// [`Authority`] if set, [`Config::DefaultOracleAuthority`] otherwise
+		pub fn authority() -> T::AccountId {
+			<Authority<T>>::get().unwrap_or_else(<T as Config>::DefaultOracleAuthority::get)
+		}
+
+		/// Authority taking effect at the block, see [`Config::AuthorityPeriod`]
+		pub fn scheduled_authority() -> Option<(T::BlockNumber, T::AccountId)> {
+			<ScheduledAuthority<T>>::get()
+		}
+
+		/// Make `authority` the [`Pallet::authority`] at the next [`Config::AuthorityPeriod`]
+		/// boundary, or right away if the period is zero
+		///
+		/// Replaces an authority change scheduled before
+		fn change_authority(authority: T::AccountId) {
+			let period = <T as Config>::AuthorityPeriod::get();
+			if period.is_zero() {
+				<ScheduledAuthority<T>>::kill();
+				Self::set_authority_now(authority);
+				return
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let at = now.saturating_sub(now % period).saturating_add(period);
+			<ScheduledAuthority<T>>::put((at, &authority));
+			Self::deposit_event(Event::AuthoritiesScheduled { authority, at });
+		}
+
+		/// Apply the authority change scheduled for `n` or earlier
+		fn activate_authority(n: BlockNumberFor<T>) -> bool {
+			match <ScheduledAuthority<T>>::get() {
+				Some((at, authority)) if at <= n => {
+					<ScheduledAuthority<T>>::kill();
+					Self::set_authority_now(authority);
+					true
+				},
+				_ => false,
+			}
+		}
+
+		/// Make `authority` the [`Pallet::authority`], dropping the [`AnnouncedPush`]
+		/// of the previous one
+		fn set_authority_now(authority: T::AccountId) {
+			<Authority<T>>::put(&authority);
+			<AnnouncedPush<T>>::kill();
+			Self::deposit_event(Event::AuthoritySet { authority });
+		}
+
+		/// Check that `origin` is signed by [`Pallet::authority`]
+		/// and pushes aren't paused
+		fn ensure_authority(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+			let who = ensure_signed(origin)?;
+			ensure!(who == Self::authority(), Error::<T>::NotAuthorized);
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+			Ok(who)
+		}
+
+		/// Payload of an external oracle `report`, see [`Config::ReportDecoder`]
+		fn decode_report(report: &[u8]) -> Result<oracle_data::Data, Error<T>> {
+			<T as Config>::ReportDecoder::decode(report, <T as Config>::Decimals::get())
+				.ok_or(Error::<T>::InvalidReport)
+		}
+
+		/// Store `data` pushed by `who`, see [`Pallet::push_data`]
+		fn do_push_data(
+			who: &T::AccountId,
+			data: oracle_data::Data,
+			confidence: Option<u128>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_data_len(&data)?;
+
+			let data_len = data.len().saturated_into();
+			let stored = Self::store(who, data, confidence, None, true)?;
+
+			// The authority & the pause flag are read by the caller
+			Ok(Some(
+				<T as Config>::WeightInfo::push_data(stored.entries, data_len) +
+					Self::store_weight(&stored) +
+					T::DbWeight::get().reads(2),
+			)
+			.into())
+		}
+
+		/// [`Event::Emitted`] for the entry stored with `sequence`, if it's due
+		///
+		/// If [`Config::EmitEvery`] is set, [`Event::EmittedSummary`] is due instead
+		/// once per that many pushes. Nothing is due for [`Config::StorageOnly`] feeds.
+		/// `data` is copied only if an event is due.
+		fn emitted_event(
+			sequence: SequenceId,
+			data: &[u8],
+			confidence: Option<u128>,
+		) -> Option<Event<T>> {
+			if <T as Config>::StorageOnly::get() {
+				return None
+			}
+
+			let emit_every = <T as Config>::EmitEvery::get();
+			if emit_every > 1 {
+				let pushes = <UnreportedPushes<T>>::mutate(|pushes| {
+					*pushes = pushes.saturating_add(1);
+					*pushes
+				});
+				if pushes < emit_every {
+					return None
+				}
+
+				<UnreportedPushes<T>>::kill();
+				Some(Event::EmittedSummary { pushes, sequence, data: data.to_vec(), confidence })
+			} else {
+				Some(Event::Emitted { sequence, data: data.to_vec(), confidence })
+			}
+		}
+
+		/// Deposit [`Pallet::emitted_event`] indexed by [`Pallet::feed_topic`]
+		/// and the hash of the submitter
+		///
+		/// Lets light clients filter oracle updates without downloading all events.
+		fn deposit_emitted(who: &T::AccountId, event: Event<T>) {
+			let event = <T as Config>::RuntimeEvent::from(event);
+			<frame_system::Pallet<T>>::deposit_event_indexed(
+				&[Self::feed_topic(), T::Hashing::hash_of(who)],
+				event.into(),
+			);
+		}
+
+		/// Topic of the events of this oracle feed, hash of [`Config::Feed`]
+		pub fn feed_topic() -> T::Hash {
+			T::Hashing::hash_of(&Self::feed_id())
+		}
+
+		/// Check that `data` fits into [`Config::MaxDataLen`]
+		fn ensure_data_len(data: &[u8]) -> Result<(), Error<T>> {
+			ensure!(
+				data.len() <= <T as Config>::MaxDataLen::get() as usize,
+				Error::<T>::DataTooLarge
+			);
+			Ok(())
+		}
+
+		/// Record a rejected push, dropping the oldest record if there is no room
+		pub(crate) fn record_rejected_push(who: T::AccountId, reason: DispatchError) {
+			let max_pushes = <T as Config>::MaxRejectedPushes::get() as usize;
+			if max_pushes == 0 {
+				return
+			}
+
+			let moment = <pallet_timestamp::Pallet<T>>::get();
+			<RejectedPushes<T>>::mutate(|pushes| {
+				if pushes.len() >= max_pushes {
+					pushes.remove(0);
+				}
+				let _ = pushes.try_push(RejectedPush { who, reason, moment });
+			});
+		}
+
+		/// The latest rejected pushes, oldest first
+		///
+		/// Lets operators find out why their pushes don't get into the feed
+		pub fn rejected_pushes(
+		) -> Vec<RejectedPush<T::AccountId, <T as pallet_timestamp::Config>::Moment>> {
+			<RejectedPushes<T>>::get().into_inner()
+		}
+
+		/// Numeric value at `moment`, linearly interpolated
+		/// between the alive entries around it
+		///
+		/// See [`oracle_data::OracleStorage::value_interpolated_at`]
+		pub fn value_interpolated_at(
+			moment: <T as pallet_timestamp::Config>::Moment,
+		) -> Option<u128> {
+			Self::events_storage()?
+				.value_interpolated_at(<pallet_timestamp::Pallet<T>>::get(), moment)
+		}
+
+		/// The newest alive data saved not after `moment`,
+		/// together with its age at `moment`
+		///
+		/// Gives a deterministic answer for settlement at an arbitrary moment
+		pub fn value_at_or_before(
+			moment: <T as pallet_timestamp::Config>::Moment,
+		) -> Option<(oracle_data::Data, <T as pallet_timestamp::Config>::Moment)> {
+			Self::events_storage()?
+				.value_at_or_before(<pallet_timestamp::Pallet<T>>::get(), moment)
+				.map(|(data, age)| (data.to_vec(), age))
+		}
+
+		/// The newest alive data, together with its age
+		///
+		/// Same as [`Pallet::value_at_or_before`] the current moment, but reads
+		/// only [`LatestValue`] instead of loading the whole [`FeedEntries`] of the feed.
+		/// Nothing is read from out-of-order storage: the cache is dropped once it's
+		/// detected, see [`Pallet::flag_corrupted`], & the fallback checks the order.
+		pub fn latest_value() -> Option<(oracle_data::Data, <T as pallet_timestamp::Config>::Moment)>
+		{
+			let now = <pallet_timestamp::Pallet<T>>::get();
+			match <LatestValue<T>>::get() {
+				// Data saved after `now`, e.g. if the clock went backwards, isn't alive
+				Some((saved_at, data)) => {
+					let age = now.saturating_sub(saved_at);
+					(saved_at <= now && age < Self::lifetime()).then_some((data, age))
+				},
+				None => Self::value_at_or_before(now),
+			}
+		}
+
+		/// The newest alive data, only if it's younger than `max_age`
+		///
+		/// Gives consumers staleness protection right in the read path
+		pub fn latest_if_fresh(
+			max_age: <T as pallet_timestamp::Config>::Moment,
+		) -> Option<oracle_data::Data> {
+			let (data, age) = Self::latest_value()?;
+			(age < max_age).then_some(data)
+		}
+
+		/// Asset pair published by this oracle
+		pub fn feed_id() -> FeedId {
+			<T as Config>::Feed::get()
+		}
+
+		/// Decode a numeric payload as a [`Price`] with [`Config::Decimals`]
+		pub fn decode_price(data: &[u8]) -> Option<Price> {
+			Price::from_payload(data, <T as Config>::Decimals::get())
+		}
+
+		/// Price version of [`Pallet::value_at_or_before`]
+		pub fn price_at_or_before(
+			moment: <T as pallet_timestamp::Config>::Moment,
+		) -> Option<(Price, <T as pallet_timestamp::Config>::Moment)> {
+			let (data, age) = Self::value_at_or_before(moment)?;
+			Some((Self::decode_price(&data)?, age))
+		}
+
+		/// Bounded version of [`Pallet::oracle_data`]
+		///
+		/// Returns no more than [`Config::MaxQueryEntries`] entries and
+		/// [`Config::MaxQueryBytes`] bytes per call, so external queries
+		/// can't make the runtime materialize the whole storage at once.
+		/// Pass the returned [`oracle_data::Page::next`] to read the rest.
+		pub fn oracle_data_page(
+			cursor: Option<oracle_data::Cursor<<T as pallet_timestamp::Config>::Moment>>,
+		) -> Option<oracle_data::Page<<T as pallet_timestamp::Config>::Moment>> {
+			Some(Self::events_storage()?.page(
+				<pallet_timestamp::Pallet<T>>::get(),
+				cursor,
+				<T as Config>::MaxQueryEntries::get(),
+				<T as Config>::MaxQueryBytes::get(),
+			))
+		}
+
+		/// Bounded version of [`Pallet::oracle_entries`], see [`Pallet::oracle_data_page`]
+		///
+		/// Payloads which aren't entries are skipped, but still count towards the limits,
+		/// so a page may hold fewer entries than [`Config::MaxQueryEntries`].
+		pub fn oracle_entries_page(
+			cursor: Option<oracle_data::Cursor<<T as pallet_timestamp::Config>::Moment>>,
+		) -> Option<oracle_data::Page<<T as pallet_timestamp::Config>::Moment, T::Entry>> {
+			let page = Self::oracle_data_page(cursor)?;
+			Some(oracle_data::Page {
+				data: page.data.iter().filter_map(|data| Self::decode_entry(data)).collect(),
+				next: page.next,
+			})
+		}
+	}
+
+	/// [`Event::Emitted`] is deposited indexed, see [`Pallet::feed_topic`]
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `data` is stored, `sequence` refers to the stored entry,
+		/// see [`Pallet::entry_by_sequence`] & [`Pallet::push_data_with_confidence`]
+		Emitted {
+			sequence: SequenceId,
+			data: oracle_data::Data,
+			confidence: Option<u128>,
+		},
+		/// Summary of the last `pushes` pushes, see [`Config::EmitEvery`]
+		EmittedSummary {
+			pushes: u32,
+			sequence: SequenceId,
+			data: oracle_data::Data,
+			confidence: Option<u128>,
+		},
+		LifetimeSet {
+			lifetime: <T as pallet_timestamp::Config>::Moment,
+		},
+		/// Data observed at `observed_at` was accepted `latency` later at `accepted_at`,
+		/// see [`Pallet::push_data_observed`]
+		SubmissionLatency {
+			observed_at: <T as pallet_timestamp::Config>::Moment,
+			accepted_at: <T as pallet_timestamp::Config>::Moment,
+			latency: <T as pallet_timestamp::Config>::Moment,
+		},
+		/// Result of BandChain `request_id` agreed on by `ans_count` validators was relayed
+		BandResultRelayed {
+			request_id: u64,
+			ans_count: u64,
+		},
+		/// `lifetime` is used instead of [`Pallet::lifetime`] until `expires_at`
+		RetentionOverrideSet {
+			lifetime: <T as pallet_timestamp::Config>::Moment,
+			expires_at: <T as pallet_timestamp::Config>::Moment,
+		},
+		AuthoritySet {
+			authority: T::AccountId,
+		},
+		PausedSet {
+			paused: bool,
+		},
+		DataReleased {
+			sequence: SequenceId,
+			data: oracle_data::Data,
+			saved_at: <T as pallet_timestamp::Config>::Moment,
+			confidence: Option<u128>,
+		},
+		PushAnnounced {
+			hash: T::Hash,
+		},
+		/// Chunk of the SCALE encoded stored data with `hash`,
+		/// see [`Pallet::import_snapshot`]
+		SnapshotExported {
+			hash: T::Hash,
+			chunk: u32,
+			chunks: u32,
+			data: Vec<u8>,
+		},
+		/// Snapshot with `hash` replaced stored data
+		SnapshotImported {
+			hash: T::Hash,
+			entries: u32,
+		},
+		/// Payload of the entry with `sequence` number was blanked, `hash` is kept in [`Redacted`]
+		EntryRedacted {
+			sequence: SequenceId,
+			hash: T::Hash,
+		},
+		/// `new_owner` can accept the feed ownership by [`Pallet::accept_feed_ownership`]
+		FeedOwnershipOffered {
+			new_owner: T::AccountId,
+		},
+		ForceCleaned {
+			before: <T as pallet_timestamp::Config>::Moment,
+			removed: u32,
+		},
+		/// [`FeedEntries`] is out of order, see [`Pallet::resort_storage`]
+		StorageCorrupted,
+		/// Order of [`FeedEntries`] is restored by [`Pallet::resort_storage`]
+		StorageResorted {
+			entries: u32,
+		},
+		/// `who` pushed a duplicate of the alive entry with `original` sequence number,
+		/// see [`Config::OnDuplicate`]
+		DuplicateSubmitted {
+			who: T::AccountId,
+			original: SequenceId,
+		},
+		/// `authority` becomes [`Pallet::authority`] at block `at`,
+		/// see [`Config::AuthorityPeriod`]
+		AuthoritiesScheduled {
+			authority: T::AccountId,
+			at: T::BlockNumber,
+		},
+		/// Stored data of the feed is deleted, `entries` keys of [`FeedEntries`] are removed,
+		/// see [`Pallet::delete_feed_data`]
+		FeedDataDeleted {
+			entries: u32,
+		},
+		/// [`Pallet::lifetime`] isn't overridden anymore, see [`Pallet::set_retention_override`]
+		RetentionOverrideCleared,
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Origin isn't the oracle authority
+		NotAuthorized,
+		/// Storage already contains newer data
+		StaleSubmission,
+		/// Payload is longer than [`Config::MaxDataLen`]
+		DataTooLarge,
+		/// Storage already contains [`Config::MaxEntries`] entries
+		StorageFull,
+		/// Pushes are paused by [`Pallet::set_paused`]
+		Paused,
+		/// Data hash doesn't match [`AnnouncedPush`]
+		NotAnnounced,
+		/// Caller isn't offered the feed ownership, see [`Pallet::transfer_feed_ownership`]
+		NotPendingAuthority,
+		/// Snapshot chunk doesn't exist or the imported snapshot can't be used
+		InvalidSnapshot,
+		/// No stored entry with the sequence number
+		EntryNotFound,
+		/// VAA isn't signed by a quorum of [`Config::WormholeGuardians`] or is malformed
+		InvalidVaa,
+		/// VAA isn't emitted by [`Config::VaaEmitter`]
+		UnknownEmitter,
+		/// Relayed report can't be decoded by [`Config::ReportDecoder`]
+		InvalidReport,
+		/// [`FeedEntries`] is out of order, see [`Pallet::resort_storage`]
+		StorageCorrupted,
+		/// Payload equals an alive entry's, see [`Config::OnDuplicate`]
+		DuplicateData,
+		/// Push is vetoed by [`Config::PrePushFilter`]
+		PushFiltered,
+		/// Feed isn't [`Config::Feed`]
+		UnknownFeed,
+		/// Release moment is further than [`Config::MaxReleaseDelay`] ahead
+		ReleaseTooLate,
+		/// Relayed result is agreed on by less than [`Config::MinBandAnswers`] validators
+		NotEnoughAnswers,
+	}
+
+	impl<T> From<oracle_data::Error> for Error<T> {
+		fn from(item: oracle_data::Error) -> Self {
+			match item {
+				oracle_data::Error::AttemptToInsertHistoricalData => Self::StaleSubmission,
+			}
+		}
+	}
+
+	/// Version of the storage layout, see [`crate::migrations`]
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	/// Pallet Struct
+	///
+	/// Remove storage info, because pallet storage hasn't got stable size
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	impl<T: Config> PriceProvider<<T as pallet_timestamp::Config>::Moment> for Pallet<T> {
+		fn feed_id() -> FeedId {
+			<T as Config>::Feed::get()
+		}
+
+		fn latest_price() -> Option<(Price, <T as pallet_timestamp::Config>::Moment)> {
+			let (data, age) = Self::latest_value()?;
+			Some((Self::decode_price(&data)?, age))
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Activate the scheduled authority, see [`Config::AuthorityPeriod`],
+		/// & release embargoed data, see [`Config::VisibilityDelay`]
+		///
+		/// The timestamp isn't set yet, so the moment of the previous block is used
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let activation_weight = if Self::activate_authority(n) {
+				T::DbWeight::get().reads_writes(1, 3)
+			} else {
+				T::DbWeight::get().reads(1)
+			};
+
+			let leaves = <MmrLeaves<T>>::get();
+			let released = Self::release(<pallet_timestamp::Pallet<T>>::get());
+			let (appended, mmr_nodes) = Self::appended_since(leaves);
+
+			// The clock, both queues & the MMR leaves are read even if nothing is released
+			activation_weight +
+				T::DbWeight::get().reads(4) +
+				Self::release_weight(released) +
+				Self::append_weight(appended, mmr_nodes)
+		}
+
+		/// Clean outdated data left by pushes, if the block has enough weight left
+		///
+		/// Every [`Config::ArchivePruneInterval`] blocks [`Archive`] is pruned
+		/// with the weight left after that
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used = Self::idle_clean(remaining_weight);
+
+			let interval = <T as Config>::ArchivePruneInterval::get();
+			if interval.is_zero() || !(n % interval).is_zero() {
+				return used
+			}
+
+			used.saturating_add(Self::idle_prune_archive(remaining_weight.saturating_sub(used)))
+		}
+
+		/// Log the size of the oracle storage & its proof size contribution,
+		/// so [`Config::MaxEntries`] & [`Config::MaxDataLen`] can be checked against real data
+		#[cfg(feature = "try-runtime")]
+		fn try_state(n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::log_storage_size(n);
+			Ok(())
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Clean outdated data from pallet's storage
+		///
+		/// No more than `max_items` oldest entries are cleaned per call,
+		/// so a big backlog can be cleaned in several calls.
+		/// Unused weight is refunded.
+		///
+		/// Method call allowed only for [`Config::CleanOrigin`]
+		#[pallet::weight(
+			Pallet::<T>::clean_weight((*max_items).min(<T as Config>::MaxEntries::get()))
+		)]
+		pub fn clean_outdated_data(
+			origin: OriginFor<T>,
+			max_items: u32,
+		) -> DispatchResultWithPostInfo {
+			<T as Config>::CleanOrigin::ensure_origin(origin)?;
+			let cleaned = Self::clean(max_items)?;
+
+			Ok(Some(Self::clean_weight(cleaned)).into())
+		}
+
+		/// Push oracle data
+		/// Method deposite [`Event::Emitted`] & store data to pallet storage
+		///
+		/// Outdated data is cleaned in [`Hooks::on_idle`], or in place
+		/// once [`Config::MaxPendingPrunes`] pushes are waiting for it.
+		/// Weight is charged for a full storage and refunded
+		/// to the actual amount of stored, released & cleaned entries.
+		///
+		/// Method call allowed only for [`Pallet::authority`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::push_data(
+				<T as Config>::MaxEntries::get(),
+				data.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(true) +
+				T::DbWeight::get().reads(2)
+		)]
+		pub fn push_data(
+			origin: OriginFor<T>,
+			data: oracle_data::Data,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_authority(origin)?;
+			Self::do_push_data(&who, data, None)
+		}
+
+		/// Push oracle data without cleaning outdated data
+		///
+		/// Cheaper version of [`Pallet::push_data`] for latency-critical
+		/// submissions. Outdated data is never cleaned in place, it's still
+		/// hidden from reads, but stays in storage until [`Hooks::on_idle`]
+		/// or [`Pallet::clean_outdated_data`] gets to it.
+		///
+		/// Method call allowed only for [`Pallet::authority`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::push_data_fast(
+				<T as Config>::MaxEntries::get(),
+				data.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(false) +
+				T::DbWeight::get().reads(2)
+		)]
+		pub fn push_data_fast(
+			origin: OriginFor<T>,
+			data: oracle_data::Data,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_authority(origin)?;
+			Self::ensure_data_len(&data)?;
+
+			let data_len = data.len().saturated_into();
+			let stored = Self::store(&who, data, None, None, false)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::push_data_fast(stored.entries, data_len) +
+					Self::store_weight(&stored) +
+					T::DbWeight::get().reads(2),
+			)
+			.into())
+		}
+
+		/// Set lifetime of oracle data
+		///
+		/// Applies to already stored data as well
+		///
+		/// Method call allowed only for [`Config::SetLifetimeOrigin`]
+		#[pallet::weight(<T as Config>::WeightInfo::set_lifetime() + T::DbWeight::get().writes(1))]
+		pub fn set_lifetime(
+			origin: OriginFor<T>,
+			lifetime: <T as pallet_timestamp::Config>::Moment,
+		) -> DispatchResult {
+			<T as Config>::SetLifetimeOrigin::ensure_origin(origin)?;
+			<Lifetime<T>>::put(lifetime);
+			Self::deposit_event(Event::LifetimeSet { lifetime });
+
+			Ok(())
+		}
+
+		/// Temporarily set lifetime of oracle data for `duration`
+		///
+		/// E.g. extends the window of stored data during an investigation. Layered on
+		/// top of [`Pallet::set_lifetime`], which applies again once the override expires.
+		/// Replaces the previous override, zero `duration` removes it.
+		///
+		/// Method call allowed only for [`Config::SetLifetimeOrigin`]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retention_override() + T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_retention_override(
+			origin: OriginFor<T>,
+			lifetime: <T as pallet_timestamp::Config>::Moment,
+			duration: <T as pallet_timestamp::Config>::Moment,
+		) -> DispatchResult {
+			<T as Config>::SetLifetimeOrigin::ensure_origin(origin)?;
+
+			if duration.is_zero() {
+				<RetentionOverride<T>>::kill();
+				Self::deposit_event(Event::RetentionOverrideCleared);
+				return Ok(())
+			}
+
+			let expires_at = <pallet_timestamp::Pallet<T>>::get().saturating_add(duration);
+			<RetentionOverride<T>>::put((lifetime, expires_at));
+			Self::deposit_event(Event::RetentionOverrideSet { lifetime, expires_at });
+
+			Ok(())
+		}
+
+		/// Set account allowed to push data
+		///
+		/// Takes effect at the next [`Config::AuthorityPeriod`] boundary
+		///
+		/// Method call allowed only for [`Config::SetAuthorityOrigin`]
+		#[pallet::weight(<T as Config>::WeightInfo::set_authority() + T::DbWeight::get().writes(4))]
+		pub fn set_authority(origin: OriginFor<T>, authority: T::AccountId) -> DispatchResult {
+			<T as Config>::SetAuthorityOrigin::ensure_origin(origin)?;
+			<PendingAuthority<T>>::kill();
+			Self::change_authority(authority);
+
+			Ok(())
+		}
+
+		/// Pause or resume pushes
+		///
+		/// Reads & cleanup aren't affected
+		///
+		/// Method call allowed only for [`Config::PauseOrigin`]
+		#[pallet::weight(<T as Config>::WeightInfo::set_paused() + T::DbWeight::get().writes(1))]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			<T as Config>::PauseOrigin::ensure_origin(origin)?;
+			<Paused<T>>::put(paused);
+			Self::deposit_event(Event::PausedSet { paused });
+
+			Ok(())
+		}
+
+		/// Clean all data saved before `before`, regardless of its lifetime
+		///
+		/// Works even if the clock went backwards and [`Pallet::clean_outdated_data`]
+		/// fails, so it can be used to recover from clock incidents
+		/// or to remove data by governance decision. Removed entries are
+		/// archived & unindexed the same way as pruned ones.
+		/// Unused weight is refunded.
+		///
+		/// Method call allowed only for root
+		#[pallet::weight(
+			<T as Config>::WeightInfo::force_clean(<T as Config>::MaxEntries::get()) +
+				T::DbWeight::get().reads_writes(
+					6 + 2 * u64::from(<T as Config>::MaxEntries::get()),
+					5 + 4 * u64::from(<T as Config>::MaxEntries::get())
+				)
+		)]
+		pub fn force_clean(
+			origin: OriginFor<T>,
+			before: <T as pallet_timestamp::Config>::Moment,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(!<Corrupted<T>>::get(), Error::<T>::StorageCorrupted);
+
+			let (removed, _) = Self::remove_oldest(u32::MAX, |entry| entry.saved_at() < before)?;
+			if Self::is_storage_empty() {
+				<PendingPrunes<T>>::kill();
+			}
+			Self::deposit_event(Event::ForceCleaned { before, removed });
+
+			Ok(Some(
+				<T as Config>::WeightInfo::force_clean(removed) +
+					T::DbWeight::get()
+						.reads_writes(6 + 2 * u64::from(removed), 5 + 4 * u64::from(removed)),
+			)
+			.into())
+		}
+
+		/// Push oracle data hidden from reads until `release_at`
+		///
+		/// Allows to commit to data that must not leak early. Data is released by
+		/// [`Hooks::on_initialize`] or a later push, with [`Event::DataReleased`] deposited,
+		/// and is stored as of `release_at`. Other data isn't held back by it, but no data
+		/// is released before [`Config::VisibilityDelay`] has passed.
+		///
+		/// `release_at` can't be further than [`Config::MaxReleaseDelay`] ahead.
+		///
+		/// Method call allowed only for [`Pallet::authority`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::push_data_timelocked(
+				<T as Config>::MaxEntries::get(),
+				data.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(false) +
+				T::DbWeight::get().reads(2)
+		)]
+		pub fn push_data_timelocked(
+			origin: OriginFor<T>,
+			data: oracle_data::Data,
+			release_at: <T as pallet_timestamp::Config>::Moment,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_authority(origin)?;
+			Self::ensure_data_len(&data)?;
+			ensure!(
+				release_at <=
+					<pallet_timestamp::Pallet<T>>::get()
+						.saturating_add(<T as Config>::MaxReleaseDelay::get()),
+				Error::<T>::ReleaseTooLate
+			);
+
+			let data_len = data.len().saturated_into();
+			let stored = Self::store(&who, data, None, Some(release_at), false)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::push_data_timelocked(stored.entries, data_len) +
+					Self::store_weight(&stored) +
+					T::DbWeight::get().reads(2),
+			)
+			.into())
+		}
+
+		/// Announce the hash of data to be pushed by [`Pallet::execute_push`]
+		///
+		/// Lets a multisig or proxy authority approve the exact payload by its hash,
+		/// while the payload itself is submitted later. Replaces the previous announcement.
+		///
+		/// Method call allowed only for [`Pallet::authority`]
+		#[pallet::weight(<T as Config>::WeightInfo::announce_push() + T::DbWeight::get().writes(1))]
+		pub fn announce_push(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			Self::ensure_authority(origin)?;
+			<AnnouncedPush<T>>::put(hash);
+			Self::deposit_event(Event::PushAnnounced { hash });
+
+			Ok(())
+		}
+
+		/// Push data announced by [`Pallet::announce_push`] on behalf of [`Pallet::authority`]
+		///
+		/// Consumes the announcement, see [`Pallet::push_data`]
+		///
+		/// Method call allowed for any signed origin
+		#[pallet::weight(
+			<T as Config>::WeightInfo::push_data(
+				<T as Config>::MaxEntries::get(),
+				data.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(true) +
+				T::DbWeight::get().reads_writes(3, 1)
+		)]
+		pub fn execute_push(
+			origin: OriginFor<T>,
+			data: oracle_data::Data,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+			ensure!(
+				<AnnouncedPush<T>>::take() == Some(T::Hashing::hash(&data)),
+				Error::<T>::NotAnnounced
+			);
+
+			Self::do_push_data(&Self::authority(), data, None)
+		}
+
+		/// Restore chronological order of stored data
+		///
+		/// Recovers from out-of-order storage, e.g. left by a buggy migration,
+		/// which is neither read nor modified until then. Indexes by sequence number
+		/// follow the moved entries, see [`Pallet::renumber`].
+		///
+		/// Method call allowed only for root
+		#[pallet::weight(Pallet::<T>::resort_weight(<T as Config>::MaxEntries::get()))]
+		pub fn resort_storage(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			// Entries may move to other sequence numbers, so the whole storage is rewritten
+			let entries = Self::stored_range().map_or(0, |(first, next)| {
+				let feed = <T as Config>::Feed::get();
+				let mut entries: Vec<_> = (first..next)
+					.filter_map(|sequence| Some((sequence, <FeedEntries<T>>::get(feed, sequence)?)))
+					.collect();
+				// Stable, as [`oracle_data::OracleStorage::sort`]
+				entries.sort_by_key(|(_, entry)| entry.saved_at());
+				Self::renumber(&entries, first);
+
+				let storage: OracleStorageOf<T> =
+					entries.into_iter().map(|(_, entry)| entry).collect();
+				Self::cache_latest(&storage);
+				Self::put_storage(&storage, first);
+				storage.len().saturated_into()
+			});
+			<Corrupted<T>>::kill();
+			Self::deposit_event(Event::StorageResorted { entries });
+
+			Ok(Some(Self::resort_weight(entries)).into())
+		}
+
+		/// Offer the feed ownership, i.e. [`Pallet::authority`], to `new_owner`
+		///
+		/// The ownership is transferred once `new_owner` accepts it by
+		/// [`Pallet::accept_feed_ownership`], so it can't be lost to a wrong account.
+		/// Replaces the previous offer. Stored data is kept.
+		///
+		/// Method call allowed only for [`Pallet::authority`], even if pushes are paused
+		#[pallet::weight(
+			<T as Config>::WeightInfo::transfer_feed_ownership() + T::DbWeight::get().reads_writes(1, 1)
+		)]
+		pub fn transfer_feed_ownership(
+			origin: OriginFor<T>,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who == Self::authority(), Error::<T>::NotAuthorized);
+
+			<PendingAuthority<T>>::put(&new_owner);
+			Self::deposit_event(Event::FeedOwnershipOffered { new_owner });
+
+			Ok(())
+		}
+
+		/// Accept the feed ownership offered by [`Pallet::transfer_feed_ownership`]
+		///
+		/// Takes effect at the next [`Config::AuthorityPeriod`] boundary
+		///
+		/// Method call allowed only for the offered account
+		#[pallet::weight(
+			<T as Config>::WeightInfo::accept_feed_ownership() + T::DbWeight::get().reads_writes(1, 4)
+		)]
+		pub fn accept_feed_ownership(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				<PendingAuthority<T>>::get() == Some(who.clone()),
+				Error::<T>::NotPendingAuthority
+			);
+
+			<PendingAuthority<T>>::kill();
+			Self::change_authority(who);
+
+			Ok(())
+		}
+
+		/// Export the `chunk`-th chunk of a snapshot of stored data
+		///
+		/// The snapshot is SCALE encoded stored data, see [`OracleStorageOf`], split into chunks of
+		/// [`Config::SnapshotChunkLen`] bytes, each deposited in [`Event::SnapshotExported`]
+		/// with the hash of the whole snapshot. It can be moved to a new chain
+		/// or instance by [`Pallet::import_snapshot`].
+		///
+		/// Method call allowed only for root
+		#[pallet::weight(
+			<T as Config>::WeightInfo::export_snapshot(<T as Config>::MaxEntries::get()) +
+				T::DbWeight::get().reads(1 + u64::from(<T as Config>::MaxEntries::get()))
+		)]
+		pub fn export_snapshot(origin: OriginFor<T>, chunk: u32) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let storage = Self::load_storage().unwrap_or_default();
+			let snapshot = storage.encode();
+			let chunk_len = <T as Config>::SnapshotChunkLen::get().max(1) as usize;
+			let data = snapshot
+				.chunks(chunk_len)
+				.nth(chunk as usize)
+				.ok_or(Error::<T>::InvalidSnapshot)?
+				.to_vec();
+
+			Self::deposit_event(Event::SnapshotExported {
+				hash: T::Hashing::hash(&snapshot),
+				chunk,
+				chunks: ((snapshot.len() + chunk_len - 1) / chunk_len).saturated_into(),
+				data,
+			});
+
+			Ok(Some(
+				<T as Config>::WeightInfo::export_snapshot(storage.len().saturated_into()) +
+					T::DbWeight::get().reads(1 + storage.len() as u64),
+			)
+			.into())
+		}
+
+		/// Import a snapshot exported by [`Pallet::export_snapshot`] chunk by chunk
+		///
+		/// Chunks are appended until their concatenation matches `hash`, then the snapshot
+		/// replaces stored data. A chunk of another snapshot starts the import over.
+		/// Imported entries are numbered from zero, indexes by sequence number are rebuilt
+		/// or cleared and embargoed & timelocked data is dropped. Chunks growing the snapshot past
+		/// the max size of stored data are rejected.
+		///
+		/// Method call allowed only for root
+		#[pallet::weight(
+			<T as Config>::WeightInfo::import_snapshot(<T as Config>::MaxEntries::get(), chunk.len().saturated_into()) +
+				T::DbWeight::get().reads_writes(1, 12 + 4 * u64::from(<T as Config>::MaxEntries::get()))
+		)]
+		pub fn import_snapshot(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+			chunk: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let chunk_len = chunk.len().saturated_into();
+			let mut snapshot = match <SnapshotImport<T>>::take() {
+				Some((pending, snapshot)) if pending == hash => snapshot,
+				_ => Vec::new(),
+			};
+			snapshot.extend(chunk);
+			// No valid snapshot is bigger, so the pending one can't grow unbounded
+			ensure!(snapshot.len() <= Self::max_storage_size(), Error::<T>::InvalidSnapshot);
+
+			if T::Hashing::hash(&snapshot) != hash {
+				<SnapshotImport<T>>::put((hash, snapshot));
+				return Ok(Some(
+					<T as Config>::WeightInfo::import_snapshot(0, chunk_len) +
+						T::DbWeight::get().reads_writes(1, 1),
+				)
+				.into())
+			}
+
+			let storage = OracleStorageOf::<T>::decode_all(&mut &snapshot[..])
+				.map_err(|_| Error::<T>::InvalidSnapshot)?;
+			ensure!(
+				storage.is_sorted() && storage.len() <= <T as Config>::MaxEntries::get() as usize,
+				Error::<T>::InvalidSnapshot
+			);
+
+			let entries = storage.len().saturated_into();
+			let cleared = Self::replace_storage(storage);
+			Self::deposit_event(Event::SnapshotImported { hash, entries });
+
+			Ok(Some(
+				<T as Config>::WeightInfo::import_snapshot(entries, chunk_len) +
+					T::DbWeight::get()
+						.reads_writes(1, 12 + 3 * u64::from(entries) + u64::from(cleared)),
+			)
+			.into())
+		}
+
+		/// Blank payload of the stored entry with `sequence` number
+		///
+		/// Removes mistakenly published data, e.g. for legal reasons. The entry keeps
+		/// its place and moment with an empty payload, while the payload hash is kept
+		/// in [`Redacted`] as a tombstone. Outdated entries can be redacted until cleaned.
+		///
+		/// Method call allowed only for [`Config::RedactOrigin`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::redact_entry() + T::DbWeight::get().reads_writes(4, 4)
+		)]
+		pub fn redact_entry(origin: OriginFor<T>, sequence: SequenceId) -> DispatchResult {
+			<T as Config>::RedactOrigin::ensure_origin(origin)?;
+
+			let feed = <T as Config>::Feed::get();
+			let entry = <FeedEntries<T>>::get(feed, sequence).ok_or(Error::<T>::EntryNotFound)?;
+			let (saved_at, confidence) = (entry.saved_at(), entry.confidence());
+			<FeedEntries<T>>::insert(
+				feed,
+				sequence,
+				OracleEntryOf::<T>::new(Vec::new(), saved_at, confidence),
+			);
+			if Self::last_sequence() == Some(sequence) {
+				// A missing cache is left to the fallback, e.g. for corrupted storage
+				<LatestValue<T>>::mutate_exists(|latest| {
+					if let Some((_, data)) = latest {
+						data.clear();
+					}
+				});
+			}
+
+			let hash = T::Hashing::hash(entry.data());
+			Self::unindex(hash, sequence);
+			<Redacted<T>>::insert(sequence, hash);
+			Self::deposit_event(Event::EntryRedacted { sequence, hash });
+
+			Ok(())
+		}
+
+		/// Push oracle data observed at `observed_at`
+		///
+		/// Same as [`Pallet::push_data`], but also deposits [`Event::SubmissionLatency`],
+		/// so data latency can be measured from chain data alone. Observation moments
+		/// from the future are accepted with zero latency.
+		///
+		/// Method call allowed only for [`Pallet::authority`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::push_data(
+				<T as Config>::MaxEntries::get(),
+				data.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(true) +
+				T::DbWeight::get().reads(2)
+		)]
+		pub fn push_data_observed(
+			origin: OriginFor<T>,
+			data: oracle_data::Data,
+			observed_at: <T as pallet_timestamp::Config>::Moment,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_authority(origin)?;
+			let post_info = Self::do_push_data(&who, data, None)?;
+
+			let accepted_at = <pallet_timestamp::Pallet<T>>::get();
+			Self::deposit_event(Event::SubmissionLatency {
+				observed_at,
+				accepted_at,
+				latency: accepted_at.saturating_sub(observed_at),
+			});
+
+			Ok(post_info)
+		}
+
+		/// Push the payload of a Wormhole VAA, e.g. a Pyth price update
+		///
+		/// The VAA must be signed by a quorum of [`Config::WormholeGuardians`],
+		/// emitted by [`Config::VaaEmitter`] and be newer than the latest accepted one.
+		/// The payload is decoded by [`Config::ReportDecoder`] & stored on behalf of
+		/// [`Pallet::authority`], as it's attested by the guardians rather than the relayer.
+		///
+		/// Method call allowed for anyone, while pushes aren't paused
+		#[pallet::weight(
+			<T as Config>::WeightInfo::submit_vaa(
+				<T as Config>::WormholeGuardians::get().addresses.len().saturated_into(),
+			) + <T as Config>::WeightInfo::push_data(
+				<T as Config>::MaxEntries::get(),
+				vaa.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(true) +
+				T::DbWeight::get().reads_writes(3, 1)
+		)]
+		pub fn submit_vaa(origin: OriginFor<T>, vaa: Vec<u8>) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+
+			let guardians = <T as Config>::WormholeGuardians::get();
+			let vaa = wormhole::verify(&vaa, &guardians).map_err(|_| Error::<T>::InvalidVaa)?;
+			ensure!(
+				(vaa.emitter_chain, vaa.emitter_address) == <T as Config>::VaaEmitter::get(),
+				Error::<T>::UnknownEmitter
+			);
+			ensure!(
+				<LastVaaSequence<T>>::get().map_or(true, |last| vaa.sequence > last),
+				Error::<T>::StaleSubmission
+			);
+			<LastVaaSequence<T>>::put(vaa.sequence);
+
+			let data = Self::decode_report(&vaa.payload)?;
+			let post_info = Self::do_push_data(&Self::authority(), data, None)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::submit_vaa(guardians.addresses.len().saturated_into()) +
+					post_info.actual_weight.unwrap_or_default() +
+					T::DbWeight::get().reads_writes(1, 1),
+			)
+			.into())
+		}
+
+		/// Push the result of a BandChain oracle request
+		///
+		/// Takes the fields of a BandChain oracle result,
+		/// `result` is decoded by [`Config::ReportDecoder`] & pushed on behalf of
+		/// [`Pallet::authority`], as by [`Pallet::submit_vaa`]. Requests must be relayed
+		/// in order of their ids & agreed on by [`Config::MinBandAnswers`] validators.
+		///
+		/// Method call allowed only for [`Config::BandRelayers`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::relay_band_result(
+				<T as Config>::MaxEntries::get(),
+				result.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(true) +
+				T::DbWeight::get().reads_writes(4, 1)
+		)]
+		pub fn relay_band_result(
+			origin: OriginFor<T>,
+			request_id: u64,
+			ans_count: u64,
+			result: oracle_data::Data,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(<T as Config>::BandRelayers::contains(&who), Error::<T>::NotAuthorized);
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+			ensure!(
+				ans_count >= <T as Config>::MinBandAnswers::get(),
+				Error::<T>::NotEnoughAnswers
+			);
+			let result = Self::decode_report(&result)?;
+			Self::ensure_data_len(&result)?;
+			ensure!(
+				<LastBandRequest<T>>::get().map_or(true, |last| request_id > last),
+				Error::<T>::StaleSubmission
+			);
+			<LastBandRequest<T>>::put(request_id);
+
+			let data_len = result.len().saturated_into();
+			let stored = Self::store(&Self::authority(), result, None, None, true)?;
+			Self::deposit_event(Event::BandResultRelayed { request_id, ans_count });
+
+			Ok(Some(
+				<T as Config>::WeightInfo::relay_band_result(stored.entries, data_len) +
+					Self::store_weight(&stored) +
+					T::DbWeight::get().reads_writes(4, 1),
+			)
+			.into())
+		}
+
+		/// Push numeric oracle data with its `confidence`
+		///
+		/// Same as [`Pallet::push_data`], `confidence` is the uncertainty of the value
+		/// in the same units, so consumers can widen their margins. It's carried
+		/// through [`Event::Emitted`] & [`Pallet::confidence_by_sequence`].
+		///
+		/// Method call allowed only for [`Pallet::authority`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::push_data(
+				<T as Config>::MaxEntries::get(),
+				data.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(true) +
+				T::DbWeight::get().reads(2)
+		)]
+		pub fn push_data_with_confidence(
+			origin: OriginFor<T>,
+			data: oracle_data::Data,
+			confidence: u128,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_authority(origin)?;
+			Self::do_push_data(&who, data, Some(confidence))
+		}
+
+		/// Push a structured entry, stored SCALE encoded after [`Config::EntryVersion`]
+		///
+		/// Same as [`Pallet::push_data`], so every consumer decodes entries the same way,
+		/// see [`Pallet::oracle_entries`].
+		///
+		/// Method call allowed only for [`Pallet::authority`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::push_data(
+				<T as Config>::MaxEntries::get(),
+				entry.encoded_size().saturating_add(1).saturated_into(),
+			) + Pallet::<T>::max_store_weight(true) +
+				T::DbWeight::get().reads(2)
+		)]
+		pub fn push_entry(origin: OriginFor<T>, entry: T::Entry) -> DispatchResultWithPostInfo {
+			let who = Self::ensure_authority(origin)?;
+			Self::do_push_data(&who, Self::encode_entry(&entry), None)
+		}
+
+		/// Push oracle data on behalf of [`Pallet::authority`]
+		///
+		/// Same as [`Pallet::push_data`], for emergency manual updates by governance,
+		/// e.g. while the feeder is down. Allowed while the oracle is paused.
+		///
+		/// Method call allowed only for [`Config::ManualPushOrigin`]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::push_data(
+				<T as Config>::MaxEntries::get(),
+				data.len().saturated_into(),
+			) + Pallet::<T>::max_store_weight(true) +
+				T::DbWeight::get().reads(2)
+		)]
+		pub fn push_data_as(
+			origin: OriginFor<T>,
+			data: oracle_data::Data,
+		) -> DispatchResultWithPostInfo {
+			<T as Config>::ManualPushOrigin::ensure_origin(origin)?;
+			Self::do_push_data(&Self::authority(), data, None)
+		}
+
+		/// Delete all stored data of [`Config::Feed`]
+		///
+		/// Stored entries are removed from [`FeedEntries`] one by one, together with their
+		/// [`HashIndex`] & [`Redacted`] items. [`LastPushedId`], the cached [`LatestValue`],
+		/// queued data & pending hook work are reset as well. Sequence numbers continue
+		/// from the deleted entries, the MMR & archived data are kept.
+		///
+		/// Method call allowed only for root
+		#[pallet::weight(Pallet::<T>::delete_weight(
+			<T as Config>::MaxEntries::get(),
+			<T as Config>::MaxEntries::get()
+		))]
+		pub fn delete_feed_data(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let feed = <T as Config>::Feed::get();
+			let (first, next) = Self::stored_range().unwrap_or_default();
+			let mut entries = 0u32;
+			for sequence in first..next {
+				if let Some(entry) = <FeedEntries<T>>::take(feed, sequence) {
+					Self::unindex(T::Hashing::hash(entry.data()), sequence);
+					entries = entries.saturating_add(1);
+				}
+				<Redacted<T>>::remove(sequence);
+			}
+			// An item per pusher, items beyond the bound keep numbers of deleted entries,
+			// as items of pruned entries do
+			let pushers = <LastPushedId<T>>::clear(<T as Config>::MaxEntries::get(), None).unique;
+			<EntryCount<T>>::kill();
+			<LatestValue<T>>::kill();
+			<EmbargoedData<T>>::kill();
+			<TimelockedData<T>>::kill();
+			<PendingPrunes<T>>::kill();
+			<UnreportedPushes<T>>::kill();
+			<Corrupted<T>>::kill();
+			Self::deposit_event(Event::FeedDataDeleted { entries });
+
+			Ok(Some(Self::delete_weight(entries, pushers)).into())
+		}
 	}
 }
@@ -1,11 +1,25 @@
-use crate as pallet_simple_oracle;
-use frame_support::traits::{ConstU16, ConstU64};
+use crate::{
+	self as pallet_simple_oracle,
+	decoder::{EntryDecoder, SingleVersion},
+	feed::FeedId,
+	hooks::{PostPushHook, PrePushFilter},
+	wormhole::GuardianSet,
+	DuplicatePolicy,
+};
+use codec::{Decode, DecodeAll, Encode};
+use frame_support::{
+	traits::{ConstU16, ConstU32, ConstU64, ConstU8, IsInVec},
+	RuntimeDebug,
+};
 use frame_system as system;
+use scale_info::TypeInfo;
 use sp_core::H256;
+use sp_keystore::{testing::KeyStore, KeystoreExt};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
+use std::sync::Arc;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -50,8 +64,77 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+/// Structured payload of the mock feed
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Ticker {
+	pub price: u128,
+	pub volume: u128,
+	pub sources: u8,
+}
+
+/// Decodes tickers of the current schema & prices of the first one
+pub struct TickerDecoder;
+
+impl EntryDecoder<Ticker> for TickerDecoder {
+	fn decode(version: u8, body: &[u8]) -> Option<Ticker> {
+		match version {
+			0 => u128::decode_all(&mut &body[..]).ok().map(|price| Ticker {
+				price,
+				volume: 0,
+				sources: 0,
+			}),
+			_ => SingleVersion::<ConstU8<1>>::decode(version, body),
+		}
+	}
+}
+
+/// Rejects [`TestPushFilter::VETOED`] & replaces [`TestPushFilter::RAW`] payloads
+pub struct TestPushFilter;
+
+impl TestPushFilter {
+	pub const VETOED: &'static [u8] = b"vetoed";
+	pub const RAW: &'static [u8] = b"raw";
+	pub const FILTERED: &'static [u8] = b"filtered";
+}
+
+impl PrePushFilter<u64, u64> for TestPushFilter {
+	fn filter(_who: &u64, data: Vec<u8>, _now: &u64) -> Option<Vec<u8>> {
+		match &data[..] {
+			Self::VETOED => None,
+			Self::RAW => Some(Self::FILTERED.to_vec()),
+			_ => Some(data),
+		}
+	}
+}
+
+/// Records accepted pushes in [`ObservedPushes`]
+pub struct TestPushHook;
+
+impl PostPushHook<u64, u64> for TestPushHook {
+	fn on_push(who: &u64, data: &[u8], now: &u64) {
+		ObservedPushes::mutate(|pushes| pushes.push((*who, data.to_vec(), *now)));
+	}
+}
+
+/// Relays raw reports as [`Test::BAND_RELAYER_ID`] in benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_simple_oracle::traits::BenchmarkHelper<u64> for TestBenchmarkHelper {
+	fn band_relayer() -> u64 {
+		Test::BAND_RELAYER_ID
+	}
+
+	fn report(data_len: u32) -> Vec<u8> {
+		vec![0; data_len as usize]
+	}
+}
+
 impl Test {
 	pub const DEFAULT_ORACLE_ACCOUNT_ID: u64 = 0;
+	pub const PAUSE_ACCOUNT_ID: u64 = 100;
+	pub const BAND_RELAYER_ID: u64 = 200;
 }
 
 impl pallet_timestamp::Config for Test {
@@ -61,17 +144,80 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+frame_support::parameter_types! {
+	pub const OracleFeed: FeedId = FeedId::pair(b"BTC", b"USD");
+	pub static AuthorityPeriod: u64 = 0;
+	pub static PruneDelay: u64 = 0;
+	pub static ArchiveWindow: u64 = 0;
+	pub static MaxArchiveBytes: u32 = u32::MAX;
+	pub static VisibilityDelay: u64 = 0;
+	pub static OnDuplicate: DuplicatePolicy = DuplicatePolicy::Accept;
+	pub static SuppressUnchanged: bool = false;
+	pub static EmitEvery: u32 = 0;
+	pub static StorageOnly: bool = false;
+	pub static MaxQueryEntries: u32 = 100;
+	pub static ObservedPushes: Vec<(u64, Vec<u8>, u64)> = Vec::new();
+	pub static WormholeGuardians: GuardianSet = GuardianSet::default();
+	pub const VaaEmitter: (u16, [u8; 32]) = (26, [1; 32]);
+	pub BandRelayers: Vec<u64> = vec![Test::BAND_RELAYER_ID];
+}
+
+frame_support::ord_parameter_types! {
+	pub const PauseAccount: u64 = Test::PAUSE_ACCOUNT_ID;
+}
+
 impl pallet_simple_oracle::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type DefaultOracleAuthority = ConstU64<{ Self::DEFAULT_ORACLE_ACCOUNT_ID }>;
+	type AuthorityPeriod = AuthorityPeriod;
+	type Feed = OracleFeed;
 	type OracleDataLifetime = ConstU64<3600>;
+	type PruneDelay = PruneDelay;
+	type ArchiveWindow = ArchiveWindow;
+	type ArchivePruneInterval = ConstU64<10>;
+	type MaxArchiveDepth = ConstU32<3>;
+	type MaxArchiveBytes = MaxArchiveBytes;
+	type VisibilityDelay = VisibilityDelay;
+	type MaxReleaseDelay = ConstU64<100>;
+	type OnDuplicate = OnDuplicate;
+	type SuppressUnchanged = SuppressUnchanged;
+	type EmitEvery = EmitEvery;
+	type StorageOnly = StorageOnly;
+	type Decimals = ConstU8<2>;
+	type Entry = Ticker;
+	type EntryVersion = ConstU8<1>;
+	type EntryDecoder = TickerDecoder;
+	type PrePushFilter = TestPushFilter;
+	type PostPushHook = TestPushHook;
+	type MaxQueryEntries = MaxQueryEntries;
+	type MaxQueryBytes = ConstU32<1024>;
+	type MaxPendingPrunes = ConstU32<10>;
+	type MaxDataLen = ConstU32<64>;
+	type MaxEntries = ConstU32<4096>;
+	type MaxRejectedPushes = ConstU32<2>;
+	type SnapshotChunkLen = ConstU32<64>;
+	type WormholeGuardians = WormholeGuardians;
+	type VaaEmitter = VaaEmitter;
+	type BandRelayers = IsInVec<BandRelayers>;
+	type MinBandAnswers = ConstU64<3>;
+	type ReportDecoder = pallet_simple_oracle::decoder::RawReport;
+	type CleanOrigin = pallet_simple_oracle::EnsureAnyone<u64>;
+	type SetLifetimeOrigin = system::EnsureRoot<u64>;
+	type SetAuthorityOrigin = system::EnsureRoot<u64>;
+	type PauseOrigin = system::EnsureSignedBy<PauseAccount, u64>;
+	type RedactOrigin = system::EnsureRoot<u64>;
+	type ManualPushOrigin = system::EnsureRoot<u64>;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
+	// Benchmarks sign with generated keys, e.g. of Wormhole guardians
+	ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
@@ -1,5 +1,29 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok, error::BadOrigin, pallet_prelude::Get};
+use crate::{
+	audit::{RecordRejectedPushes, RejectedPush},
+	feed::FeedId,
+	mmr,
+	mock::*,
+	oracle_data::OracleData,
+	price::{cross_rate, Price},
+	proxy::OracleProxy,
+	test_utils,
+	traits::PriceProvider,
+	weights::WeightInfo,
+	wormhole, DuplicatePolicy, Error, Event, PendingPrunes,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::GetDispatchInfo,
+	error::BadOrigin,
+	pallet_prelude::{Get, Weight},
+	traits::{Hooks, InstanceFilter},
+};
+use sp_core::{ecdsa, Pair};
+use sp_runtime::{
+	traits::{BlakeTwo256, Dispatchable, Hash, SignedExtension},
+	DispatchError, FixedPointNumber, FixedU128, Permill,
+};
 
 const DATA: [u8; 32] = [10; 32];
 
@@ -15,8 +39,17 @@ fn push_data() {
 		assert_eq!(storage.as_slice(), [DATA.to_vec()]);
 
 		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::Emitted {
+			sequence: 0,
 			data: DATA.to_vec(),
+			confidence: None,
 		}));
+		assert_eq!(
+			System::events().last().unwrap().topics,
+			[
+				SimpleOracleModule::feed_topic(),
+				BlakeTwo256::hash_of(&Test::DEFAULT_ORACLE_ACCOUNT_ID)
+			]
+		);
 	});
 }
 
@@ -27,7 +60,7 @@ fn push_data_authority_error() {
 
 		assert_noop!(
 			SimpleOracleModule::push_data(RuntimeOrigin::signed(1), DATA.to_vec()),
-			Error::<Test>::WrongAuthority
+			Error::<Test>::NotAuthorized
 		);
 		assert_noop!(
 			SimpleOracleModule::push_data(RuntimeOrigin::none(), DATA.to_vec()),
@@ -42,17 +75,66 @@ fn push_data_authority_error() {
 	});
 }
 
+#[test]
+fn push_data_errors() {
+	new_test_ext().execute_with(|| {
+		let max_data_len = <Test as crate::Config>::MaxDataLen::get() as usize;
+		assert_noop!(
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				vec![0; max_data_len + 1],
+			),
+			Error::<Test>::DataTooLarge
+		);
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			vec![0; max_data_len],
+		));
+
+		push_at(10, &DATA);
+		Timestamp::set_timestamp(9);
+		assert_noop!(
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec(),
+			),
+			Error::<Test>::StaleSubmission
+		);
+	});
+}
+
+#[test]
+fn push_data_storage_full() {
+	new_test_ext().execute_with(|| {
+		let max_entries = <Test as crate::Config>::MaxEntries::get();
+		(1..max_entries).for_each(|_| {
+			assert_ok!(SimpleOracleModule::push_data_fast(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec(),
+			));
+		});
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+		));
+
+		assert_noop!(
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec(),
+			),
+			Error::<Test>::StorageFull
+		);
+	});
+}
+
 #[test]
 fn test_lifetime() {
 	new_test_ext().execute_with(|| {
 		let data_of_moment = |moment: u64| moment.to_be_bytes().to_vec();
-		let lifetime = <Test as crate::Config>::OracleDataLifetime::get();
+		let lifetime = data_lifetime();
 		(0..lifetime).for_each(|moment| {
-			Timestamp::set_timestamp(moment);
-			assert_ok!(SimpleOracleModule::push_data(
-				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
-				data_of_moment(moment),
-			));
+			push_at(moment, &data_of_moment(moment));
 		});
 
 		assert_eq!(
@@ -77,23 +159,19 @@ fn test_lifetime() {
 fn test_cleanup() {
 	new_test_ext().execute_with(|| {
 		let data_of_moment = |moment: u64| moment.to_be_bytes().to_vec();
-		let lifetime = <Test as crate::Config>::OracleDataLifetime::get();
+		let lifetime = data_lifetime();
 		(0..lifetime).for_each(|moment| {
-			Timestamp::set_timestamp(moment);
-			assert_ok!(SimpleOracleModule::push_data(
-				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
-				data_of_moment(moment),
-			));
+			push_at(moment, &data_of_moment(moment));
 		});
 
 		let data = Some((0..lifetime).map(data_of_moment).collect::<Vec<_>>());
 		assert_eq!(SimpleOracleModule::oracle_data(), data);
-		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(),));
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
 		assert_eq!(SimpleOracleModule::oracle_data(), data);
 
 		(lifetime..lifetime * 2).for_each(|moment| {
 			Timestamp::set_timestamp(moment);
-			assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(),));
+			assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
 
 			assert_eq!(
 				SimpleOracleModule::oracle_data(),
@@ -104,3 +182,2192 @@ fn test_cleanup() {
 		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![]));
 	});
 }
+
+#[test]
+fn test_oracle_data_page() {
+	new_test_ext().execute_with(|| {
+		assert!(SimpleOracleModule::oracle_data_page(None).is_none());
+
+		let max_entries = <Test as crate::Config>::MaxQueryEntries::get();
+		(0..(max_entries as u64 + 1)).for_each(|moment| {
+			push_at(moment, &DATA);
+		});
+
+		let page = SimpleOracleModule::oracle_data_page(None).unwrap();
+		assert_eq!(page.data.len(), (1024 / DATA.len()));
+		let next = page.next.unwrap();
+		assert_eq!(next.saved_at, (1024 / DATA.len()) as u64);
+
+		let mut data = page.data;
+		let mut cursor = Some(next);
+		while let Some(next) = cursor {
+			let page = SimpleOracleModule::oracle_data_page(Some(next)).unwrap();
+			data.extend(page.data);
+			cursor = page.next;
+		}
+		assert_eq!(Some(data), SimpleOracleModule::oracle_data());
+	});
+}
+
+#[test]
+fn test_oracle_data_at_and_range() {
+	new_test_ext().execute_with(|| {
+		assert!(SimpleOracleModule::oracle_data_at(0).is_none());
+		assert!(SimpleOracleModule::oracle_data_range(0, 1).is_none());
+
+		let data_of_moment = |moment: u64| moment.to_be_bytes().to_vec();
+		(0..10).for_each(|moment| {
+			push_at(moment, &data_of_moment(moment));
+		});
+
+		assert_eq!(SimpleOracleModule::oracle_data_at(3), Some(vec![data_of_moment(3)]));
+		assert_eq!(SimpleOracleModule::oracle_data_at(10), Some(vec![]));
+		assert_eq!(
+			SimpleOracleModule::oracle_data_range(3, 6),
+			Some((3..6).map(data_of_moment).collect::<Vec<_>>())
+		);
+
+		let lifetime = data_lifetime();
+		Timestamp::set_timestamp(lifetime + 5);
+		assert_eq!(SimpleOracleModule::oracle_data_at(3), Some(vec![]));
+		assert_eq!(
+			SimpleOracleModule::oracle_data_range(3, 8),
+			Some((6..8).map(data_of_moment).collect::<Vec<_>>())
+		);
+	});
+}
+
+#[test]
+fn test_read_cost() {
+	new_test_ext().execute_with(|| {
+		let empty = SimpleOracleModule::read_cost(0, 10);
+		assert_eq!((empty.entries, empty.bytes), (0, 0));
+
+		(0..10).for_each(|moment| {
+			push_at(moment, &DATA);
+		});
+
+		let cost = SimpleOracleModule::read_cost(3, 6);
+		assert_eq!((cost.entries, cost.bytes), (3, 3 * DATA.len() as u32));
+		assert!(cost.weight.ref_time() > empty.weight.ref_time());
+		assert_eq!(
+			cost.proof_size as usize,
+			SimpleOracleModule::load_storage().unwrap().encoded_size()
+		);
+
+		let all = SimpleOracleModule::read_cost(0, 10);
+		assert_eq!(all.entries, 10);
+		assert_eq!(all.proof_size, cost.proof_size);
+		assert!(all.weight.ref_time() > cost.weight.ref_time());
+	});
+}
+
+#[test]
+fn push_data_fast() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SimpleOracleModule::push_data_fast(RuntimeOrigin::signed(1), DATA.to_vec()),
+			Error::<Test>::NotAuthorized
+		);
+
+		let lifetime = data_lifetime();
+		[0, lifetime].into_iter().for_each(|moment| {
+			Timestamp::set_timestamp(moment);
+			assert_ok!(SimpleOracleModule::push_data_fast(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				moment.to_be_bytes().to_vec(),
+			));
+		});
+
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::Emitted {
+			sequence: 1,
+			data: lifetime.to_be_bytes().to_vec(),
+			confidence: None,
+		}));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![lifetime.to_be_bytes().to_vec()]));
+		// Outdated data stays in storage until explicit cleanup
+		let storage = SimpleOracleModule::load_storage();
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+		assert_ne!(SimpleOracleModule::load_storage(), storage);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![lifetime.to_be_bytes().to_vec()]));
+	});
+}
+
+#[test]
+fn test_deferred_cleanup() {
+	new_test_ext().execute_with(|| {
+		let lifetime = data_lifetime();
+		let push = |moment: u64| push_at(moment, &moment.to_be_bytes());
+
+		// Pushes don't check for outdated data, they're only counted
+		push(0);
+		push(lifetime);
+		assert_eq!(PendingPrunes::<Test>::get(), 2);
+
+		// Not enough weight left in the block
+		assert_eq!(SimpleOracleModule::on_idle(1, Weight::zero()), Weight::zero());
+		assert_eq!(PendingPrunes::<Test>::get(), 2);
+
+		let storage = SimpleOracleModule::load_storage();
+		SimpleOracleModule::on_idle(1, Weight::from_ref_time(u64::MAX));
+		assert_eq!(PendingPrunes::<Test>::get(), 0);
+		assert_ne!(SimpleOracleModule::load_storage(), storage);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![lifetime.to_be_bytes().to_vec()]));
+	});
+}
+
+#[test]
+fn test_in_place_cleanup() {
+	new_test_ext().execute_with(|| {
+		let lifetime = data_lifetime();
+		let max_pending = <Test as crate::Config>::MaxPendingPrunes::get() as u64;
+		let push = |moment: u64| push_at(moment, &moment.to_be_bytes());
+
+		push(0);
+		(lifetime..(lifetime + max_pending - 2)).for_each(push);
+		assert_eq!(PendingPrunes::<Test>::get() as u64, max_pending - 1);
+
+		push(lifetime + max_pending);
+		assert_eq!(PendingPrunes::<Test>::get(), 0);
+		assert_eq!(
+			SimpleOracleModule::oldest_entry(),
+			Some((lifetime.to_be_bytes().to_vec(), lifetime))
+		);
+
+		// Counted again until the next cleanup
+		push(lifetime + max_pending + 1);
+		assert_eq!(PendingPrunes::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn test_push_weight_refund() {
+	new_test_ext().execute_with(|| {
+		let lifetime = data_lifetime();
+		let max_pending = <Test as crate::Config>::MaxPendingPrunes::get() as u64;
+		let push = |moment: u64| {
+			Timestamp::set_timestamp(moment);
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				moment.to_be_bytes().to_vec(),
+			)
+			.unwrap()
+			.actual_weight
+		};
+
+		assert_eq!(push(0), Some(<() as WeightInfo>::push_data(0, 8)));
+		(lifetime..(lifetime + max_pending - 2)).zip(1..).for_each(|(moment, entries)| {
+			assert_eq!(push(moment), Some(<() as WeightInfo>::push_data(entries, 8)));
+		});
+
+		// In place cleanup is charged for the cleaned entries only
+		assert_eq!(
+			push(lifetime + max_pending),
+			Some(
+				<() as WeightInfo>::push_data(max_pending as u32 - 1, 8) +
+					<() as WeightInfo>::clean_outdated_data(1)
+			)
+		);
+	});
+}
+
+#[test]
+fn test_push_weight_release() {
+	new_test_ext().execute_with(|| {
+		VisibilityDelay::set(10);
+		push_at(1, b"1");
+		VisibilityDelay::set(0);
+
+		// The push is charged for the entry it released as well
+		Timestamp::set_timestamp(11);
+		let info = SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			b"2".to_vec(),
+		)
+		.unwrap();
+		assert_eq!(
+			info.actual_weight,
+			Some(<() as WeightInfo>::push_data(1, 1) + <() as WeightInfo>::release_data(1))
+		);
+		assert_eq!(SimpleOracleModule::stored_entries(), 2);
+	});
+}
+
+#[test]
+fn test_bounded_cleanup() {
+	new_test_ext().execute_with(|| {
+		let lifetime = data_lifetime();
+		let push = |moment: u64| push_at(moment, &moment.to_be_bytes());
+
+		(0..5).for_each(push);
+		push(lifetime + 5);
+		assert_eq!(PendingPrunes::<Test>::get(), 6);
+
+		let storage = SimpleOracleModule::load_storage();
+		let info = SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), 2).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::clean_outdated_data(2)));
+		assert_ne!(SimpleOracleModule::load_storage(), storage);
+		// Some outdated data is still waiting for cleanup
+		assert_eq!(PendingPrunes::<Test>::get(), 6);
+
+		let info = SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), 10).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::clean_outdated_data(3)));
+		assert_eq!(PendingPrunes::<Test>::get(), 0);
+
+		let storage = SimpleOracleModule::load_storage();
+		let info = SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), 10).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::clean_outdated_data(0)));
+		assert_eq!(SimpleOracleModule::load_storage(), storage);
+		assert_eq!(
+			SimpleOracleModule::oracle_data(),
+			Some(vec![(lifetime + 5).to_be_bytes().to_vec()])
+		);
+	});
+}
+
+#[test]
+fn test_value_interpolated_at() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(SimpleOracleModule::value_interpolated_at(0), None);
+
+		[(0, 1_000u128), (100, 2_000u128)].into_iter().for_each(|(moment, value)| {
+			push_at(moment, &value.encode());
+		});
+
+		assert_eq!(SimpleOracleModule::value_interpolated_at(0), Some(1_000));
+		assert_eq!(SimpleOracleModule::value_interpolated_at(25), Some(1_250));
+		assert_eq!(SimpleOracleModule::value_interpolated_at(100), Some(2_000));
+		assert_eq!(SimpleOracleModule::value_interpolated_at(101), None);
+	});
+}
+
+#[test]
+fn test_value_at_or_before() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(SimpleOracleModule::value_at_or_before(0), None);
+
+		[0, 100].into_iter().for_each(|moment: u64| {
+			push_at(moment, &moment.to_be_bytes());
+		});
+
+		assert_eq!(
+			SimpleOracleModule::value_at_or_before(0),
+			Some((0u64.to_be_bytes().to_vec(), 0))
+		);
+		assert_eq!(
+			SimpleOracleModule::value_at_or_before(99),
+			Some((0u64.to_be_bytes().to_vec(), 99))
+		);
+		assert_eq!(
+			SimpleOracleModule::value_at_or_before(150),
+			Some((100u64.to_be_bytes().to_vec(), 50))
+		);
+	});
+}
+
+#[test]
+fn test_price_at_or_before() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(SimpleOracleModule::price_at_or_before(0), None);
+
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			12_345u128.encode(),
+		));
+
+		// Mock runtime uses 2 decimals
+		assert_eq!(
+			SimpleOracleModule::price_at_or_before(10),
+			Some((Price(FixedU128::saturating_from_rational(12_345, 100)), 10))
+		);
+
+		push_at(20, b"not a number");
+		assert_eq!(SimpleOracleModule::price_at_or_before(20), None);
+	});
+}
+
+#[test]
+fn test_cross_rate() {
+	struct EurUsd;
+	impl PriceProvider<u64> for EurUsd {
+		fn feed_id() -> FeedId {
+			FeedId::pair(b"EUR", b"USD")
+		}
+
+		fn latest_price() -> Option<(Price, u64)> {
+			Some((Price(FixedU128::saturating_from_rational(125, 100)), 5))
+		}
+	}
+
+	struct EurGbp;
+	impl PriceProvider<u64> for EurGbp {
+		fn feed_id() -> FeedId {
+			FeedId::pair(b"EUR", b"GBP")
+		}
+
+		fn latest_price() -> Option<(Price, u64)> {
+			Some((Price(FixedU128::from_u32(1)), 0))
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(cross_rate::<u64, SimpleOracleModule, EurUsd>(100), None);
+
+		// 25_000.00 USD per BTC with 2 decimals
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			2_500_000u128.encode(),
+		));
+		Timestamp::set_timestamp(10);
+
+		assert_eq!(
+			cross_rate::<u64, SimpleOracleModule, EurUsd>(10),
+			Some((FeedId::pair(b"BTC", b"EUR"), Price(FixedU128::from_u32(20_000))))
+		);
+		// BTC/USD price is stale
+		assert_eq!(cross_rate::<u64, SimpleOracleModule, EurUsd>(9), None);
+		// EUR/USD price is stale
+		assert_eq!(cross_rate::<u64, EurUsd, SimpleOracleModule>(4), None);
+		// Different quote assets
+		assert_eq!(cross_rate::<u64, SimpleOracleModule, EurGbp>(100), None);
+	});
+}
+
+#[test]
+fn test_latest_if_fresh() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(SimpleOracleModule::latest_if_fresh(10), None);
+
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+		));
+		assert_eq!(SimpleOracleModule::latest_if_fresh(1), Some(DATA.to_vec()));
+
+		Timestamp::set_timestamp(10);
+		assert_eq!(SimpleOracleModule::latest_if_fresh(11), Some(DATA.to_vec()));
+		assert_eq!(SimpleOracleModule::latest_if_fresh(10), None);
+
+		// Outdated data is never fresh
+		Timestamp::set_timestamp(data_lifetime());
+		assert_eq!(SimpleOracleModule::latest_if_fresh(u64::MAX), None);
+	});
+}
+
+/// Dispatch `call` signed by `who` the way the executive does, through [`RecordRejectedPushes`]
+fn dispatch_recorded(who: u64, call: RuntimeCall) {
+	let info = call.get_dispatch_info();
+	let pre = RecordRejectedPushes::<Test>::new().pre_dispatch(&who, &call, &info, 0).unwrap();
+	let (post_info, result) = match call.dispatch(RuntimeOrigin::signed(who)) {
+		Ok(post_info) => (post_info, Ok(())),
+		Err(err) => (err.post_info, Err(err.error)),
+	};
+	RecordRejectedPushes::<Test>::post_dispatch(Some(pre), &info, &post_info, 0, &result).unwrap();
+}
+
+#[test]
+fn test_rejected_pushes() {
+	new_test_ext().execute_with(|| {
+		let push = RuntimeCall::SimpleOracleModule(crate::Call::push_data { data: DATA.to_vec() });
+		let not_authorized: DispatchError = Error::<Test>::NotAuthorized.into();
+
+		dispatch_recorded(Test::DEFAULT_ORACLE_ACCOUNT_ID, push.clone());
+		// Other calls are never recorded
+		dispatch_recorded(1, RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 10 }));
+		assert!(SimpleOracleModule::rejected_pushes().is_empty());
+
+		dispatch_recorded(1, push.clone());
+		Timestamp::set_timestamp(10);
+		dispatch_recorded(2, push);
+		dispatch_recorded(
+			3,
+			RuntimeCall::SimpleOracleModule(crate::Call::push_data_fast { data: DATA.to_vec() }),
+		);
+
+		// Only the latest `MaxRejectedPushes` are kept
+		assert_eq!(
+			SimpleOracleModule::rejected_pushes(),
+			[
+				RejectedPush { who: 2, reason: not_authorized, moment: 10 },
+				RejectedPush { who: 3, reason: not_authorized, moment: 10 },
+			]
+		);
+		assert_eq!(SimpleOracleModule::oracle_data().unwrap(), [DATA.to_vec()]);
+	});
+}
+
+#[test]
+fn test_set_lifetime() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SimpleOracleModule::set_lifetime(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				10
+			),
+			BadOrigin
+		);
+
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+		));
+		Timestamp::set_timestamp(10);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+
+		assert_ok!(SimpleOracleModule::set_lifetime(RuntimeOrigin::root(), 10));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::LifetimeSet {
+			lifetime: 10,
+		}));
+		assert_eq!(SimpleOracleModule::lifetime(), 10);
+		// Already stored data is outdated with the new lifetime
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![]));
+	});
+}
+
+#[test]
+fn test_retention_override() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SimpleOracleModule::set_retention_override(RuntimeOrigin::signed(1), 100, 10),
+			BadOrigin
+		);
+
+		assert_ok!(SimpleOracleModule::set_lifetime(RuntimeOrigin::root(), 10));
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+		));
+		Timestamp::set_timestamp(10);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![]));
+
+		assert_ok!(SimpleOracleModule::set_retention_override(RuntimeOrigin::root(), 100, 10));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::RetentionOverrideSet {
+			lifetime: 100,
+			expires_at: 20,
+		}));
+		assert_eq!(SimpleOracleModule::lifetime(), 100);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+
+		// Expires automatically
+		Timestamp::set_timestamp(20);
+		assert_eq!(SimpleOracleModule::lifetime(), 10);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![]));
+
+		Timestamp::set_timestamp(15);
+		assert_ok!(SimpleOracleModule::set_retention_override(RuntimeOrigin::root(), 100, 0));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(
+			Event::RetentionOverrideCleared,
+		));
+		assert_eq!(SimpleOracleModule::lifetime(), 10);
+	});
+}
+
+#[test]
+fn test_set_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SimpleOracleModule::set_authority(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				1
+			),
+			BadOrigin
+		);
+
+		assert_ok!(SimpleOracleModule::set_authority(RuntimeOrigin::root(), 1));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::AuthoritySet {
+			authority: 1,
+		}));
+		assert_eq!(SimpleOracleModule::authority(), 1);
+
+		assert_noop!(
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec()
+			),
+			Error::<Test>::NotAuthorized
+		);
+		assert_ok!(SimpleOracleModule::push_data(RuntimeOrigin::signed(1), DATA.to_vec()));
+	});
+}
+
+#[test]
+fn test_authority_period() {
+	new_test_ext().execute_with(|| {
+		AuthorityPeriod::set(10);
+		System::set_block_number(3);
+		assert_ok!(SimpleOracleModule::set_authority(RuntimeOrigin::root(), 5));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::AuthoritiesScheduled {
+			authority: 5,
+			at: 10,
+		}));
+
+		// The current authority can still push until the boundary
+		SimpleOracleModule::on_initialize(9);
+		assert_eq!(SimpleOracleModule::authority(), Test::DEFAULT_ORACLE_ACCOUNT_ID);
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+		));
+
+		SimpleOracleModule::on_initialize(10);
+		assert_eq!(SimpleOracleModule::authority(), 5);
+		assert_eq!(SimpleOracleModule::scheduled_authority(), None);
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::AuthoritySet {
+			authority: 5,
+		}));
+
+		// Scheduled at a boundary, the change waits for the next one
+		System::set_block_number(10);
+		assert_ok!(SimpleOracleModule::set_authority(RuntimeOrigin::root(), 6));
+		assert_eq!(SimpleOracleModule::scheduled_authority(), Some((20, 6)));
+	});
+}
+
+#[test]
+fn test_transfer_feed_ownership() {
+	new_test_ext().execute_with(|| {
+		let owner = Test::DEFAULT_ORACLE_ACCOUNT_ID;
+		assert_noop!(
+			SimpleOracleModule::transfer_feed_ownership(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NotAuthorized
+		);
+		assert_ok!(SimpleOracleModule::push_data(RuntimeOrigin::signed(owner), DATA.to_vec()));
+
+		assert_ok!(SimpleOracleModule::transfer_feed_ownership(RuntimeOrigin::signed(owner), 1));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::FeedOwnershipOffered {
+			new_owner: 1,
+		}));
+		// Nothing changes until the offer is accepted
+		assert_eq!(SimpleOracleModule::authority(), owner);
+		assert_noop!(
+			SimpleOracleModule::accept_feed_ownership(RuntimeOrigin::signed(2)),
+			Error::<Test>::NotPendingAuthority
+		);
+
+		assert_ok!(SimpleOracleModule::accept_feed_ownership(RuntimeOrigin::signed(1)));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::AuthoritySet {
+			authority: 1,
+		}));
+		assert_eq!(SimpleOracleModule::authority(), 1);
+		assert_noop!(
+			SimpleOracleModule::accept_feed_ownership(RuntimeOrigin::signed(1)),
+			Error::<Test>::NotPendingAuthority
+		);
+
+		// History is kept
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+		assert_noop!(
+			SimpleOracleModule::push_data(RuntimeOrigin::signed(owner), DATA.to_vec()),
+			Error::<Test>::NotAuthorized
+		);
+		assert_ok!(SimpleOracleModule::push_data(RuntimeOrigin::signed(1), DATA.to_vec()));
+	});
+}
+
+#[test]
+fn test_set_paused() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SimpleOracleModule::set_paused(RuntimeOrigin::root(), true), BadOrigin);
+
+		assert_ok!(SimpleOracleModule::set_paused(
+			RuntimeOrigin::signed(Test::PAUSE_ACCOUNT_ID),
+			true
+		));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::PausedSet {
+			paused: true,
+		}));
+
+		assert_noop!(
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec()
+			),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			SimpleOracleModule::push_data_fast(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec()
+			),
+			Error::<Test>::Paused
+		);
+
+		assert_ok!(SimpleOracleModule::set_paused(
+			RuntimeOrigin::signed(Test::PAUSE_ACCOUNT_ID),
+			false
+		));
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+		));
+	});
+}
+
+#[test]
+fn test_force_clean() {
+	new_test_ext().execute_with(|| {
+		ArchiveWindow::set(100);
+		[100, 200].into_iter().for_each(|moment: u64| {
+			push_at(moment, &moment.to_be_bytes());
+		});
+
+		// The clock went backwards
+		Timestamp::set_timestamp(50);
+		assert_noop!(
+			SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX),
+			Error::<Test>::StaleSubmission
+		);
+
+		assert_noop!(
+			SimpleOracleModule::force_clean(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				150
+			),
+			BadOrigin
+		);
+
+		let info = SimpleOracleModule::force_clean(RuntimeOrigin::root(), 150).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::force_clean(1)));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::ForceCleaned {
+			before: 150,
+			removed: 1,
+		}));
+		// Removed entries are unindexed & archived as pruned ones
+		let hash = |moment: u64| BlakeTwo256::hash(&moment.to_be_bytes());
+		assert_eq!(crate::HashIndex::<Test>::get(hash(100)), None);
+		assert_eq!(crate::HashIndex::<Test>::get(hash(200)), Some(1));
+		assert_eq!(SimpleOracleModule::archived_at(100), vec![100u64.to_be_bytes().to_vec()]);
+
+		Timestamp::set_timestamp(200);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![200u64.to_be_bytes().to_vec()]));
+	});
+}
+
+#[test]
+fn test_prune_delay() {
+	new_test_ext().execute_with(|| {
+		PruneDelay::set(100);
+		let lifetime = data_lifetime();
+		let push = |moment: u64| push_at(moment, &moment.to_be_bytes());
+
+		push(0);
+		push(lifetime);
+		// Expired data isn't readable, but isn't pruned either
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![lifetime.to_be_bytes().to_vec()]));
+		assert_eq!(
+			SimpleOracleModule::retained_data(),
+			Some(vec![0u64.to_be_bytes().to_vec(), lifetime.to_be_bytes().to_vec()])
+		);
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+		assert_eq!(SimpleOracleModule::retained_data().unwrap().len(), 2);
+		assert_eq!(PendingPrunes::<Test>::get(), 0);
+
+		push(lifetime + 100);
+		assert_eq!(PendingPrunes::<Test>::get(), 1);
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+		assert_eq!(
+			SimpleOracleModule::retained_data(),
+			Some(vec![lifetime.to_be_bytes().to_vec(), (lifetime + 100).to_be_bytes().to_vec()])
+		);
+	});
+}
+
+#[test]
+fn test_archive() {
+	new_test_ext().execute_with(|| {
+		ArchiveWindow::set(100);
+		let lifetime = data_lifetime();
+		let push = |moment: u64| push_at(moment, &moment.to_be_bytes());
+
+		push(0);
+		push(0);
+		push(50);
+		push(lifetime + 50);
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+
+		// Pruned data is moved to the archive, the hot tier keeps alive data only
+		assert_eq!(
+			SimpleOracleModule::oracle_data(),
+			Some(vec![(lifetime + 50).to_be_bytes().to_vec()])
+		);
+		assert_eq!(SimpleOracleModule::archived_moments(), vec![0, 50]);
+		assert_eq!(SimpleOracleModule::archived_at(0), vec![0u64.to_be_bytes().to_vec(); 2]);
+		assert_eq!(SimpleOracleModule::archived_at(50), vec![50u64.to_be_bytes().to_vec()]);
+
+		// The archive is pruned only every `ArchivePruneInterval` blocks
+		Timestamp::set_timestamp(lifetime + 100);
+		SimpleOracleModule::on_idle(11, Weight::from_ref_time(u64::MAX));
+		assert_eq!(SimpleOracleModule::archived_moments(), vec![0, 50]);
+
+		SimpleOracleModule::on_idle(10, Weight::from_ref_time(u64::MAX));
+		assert_eq!(SimpleOracleModule::archived_moments(), vec![50]);
+		assert!(SimpleOracleModule::archived_at(0).is_empty());
+
+		// Without a window pruned data is deleted right away
+		ArchiveWindow::set(0);
+		push(2 * lifetime + 50);
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+		assert_eq!(SimpleOracleModule::archived_moments(), vec![50]);
+	});
+}
+
+#[test]
+fn test_archive_pruning() {
+	new_test_ext().execute_with(|| {
+		ArchiveWindow::set(100);
+		let lifetime = data_lifetime();
+		for moment in [0, 0, 50, 60] {
+			Timestamp::set_timestamp(moment);
+			assert_ok!(SimpleOracleModule::push_data_fast(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				moment.to_be_bytes().to_vec(),
+			));
+		}
+
+		Timestamp::set_timestamp(lifetime + 60);
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+		assert_eq!(SimpleOracleModule::archived_bytes(), 32);
+		let archived = |moment: u64| moment.to_be_bytes().to_vec();
+		assert_eq!(
+			SimpleOracleModule::archived_range(0, 60, None),
+			crate::oracle_data::Page {
+				data: vec![archived(0), archived(0), archived(50)],
+				next: None
+			}
+		);
+		assert_eq!(
+			SimpleOracleModule::archived_range(1, 61, None),
+			crate::oracle_data::Page { data: vec![archived(50), archived(60)], next: None }
+		);
+		assert_eq!(
+			SimpleOracleModule::archived_range(60, 0, None),
+			crate::oracle_data::Page { data: vec![], next: None }
+		);
+
+		// Ranges are read in pages, even within a moment
+		MaxQueryEntries::set(1);
+		let first = SimpleOracleModule::archived_range(0, 60, None);
+		assert_eq!(first.data, vec![archived(0)]);
+		let second = SimpleOracleModule::archived_range(0, 60, first.next);
+		assert_eq!(second.data, vec![archived(0)]);
+		let third = SimpleOracleModule::archived_range(0, 60, second.next);
+		assert_eq!(third, crate::oracle_data::Page { data: vec![archived(50)], next: None });
+		MaxQueryEntries::set(100);
+
+		// Nothing is expired yet, but the oldest moments don't fit the size limit
+		MaxArchiveBytes::set(16);
+		SimpleOracleModule::on_idle(10, Weight::from_ref_time(u64::MAX));
+		assert_eq!(SimpleOracleModule::archived_moments(), vec![50, 60]);
+		assert_eq!(SimpleOracleModule::archived_bytes(), 16);
+
+		// Expired data is pruned regardless of the size
+		Timestamp::set_timestamp(lifetime + 150);
+		SimpleOracleModule::on_idle(20, Weight::from_ref_time(u64::MAX));
+		assert_eq!(SimpleOracleModule::archived_moments(), vec![60]);
+		assert_eq!(SimpleOracleModule::archived_bytes(), 8);
+	});
+}
+
+#[test]
+fn test_archive_depth() {
+	new_test_ext().execute_with(|| {
+		ArchiveWindow::set(100);
+		let lifetime = data_lifetime();
+		let max_depth = <Test as crate::Config>::MaxArchiveDepth::get() as u64;
+		for moment in 0..=max_depth {
+			Timestamp::set_timestamp(moment);
+			assert_ok!(SimpleOracleModule::push_data_fast(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				moment.to_be_bytes().to_vec(),
+			));
+		}
+
+		Timestamp::set_timestamp(lifetime + max_depth);
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+
+		// The oldest moment made room for the newest one
+		assert_eq!(SimpleOracleModule::archived_moments(), (1..=max_depth).collect::<Vec<_>>());
+		assert!(SimpleOracleModule::archived_at(0).is_empty());
+		assert_eq!(
+			SimpleOracleModule::archived_at(max_depth),
+			vec![max_depth.to_be_bytes().to_vec()]
+		);
+	});
+}
+
+#[test]
+fn test_visibility_delay() {
+	new_test_ext().execute_with(|| {
+		VisibilityDelay::set(10);
+		let push = |moment: u64| push_at(moment, &moment.to_be_bytes());
+
+		push(0);
+		push(5);
+		assert_eq!(SimpleOracleModule::oracle_data(), None);
+		// Embargoed data doesn't leak through events
+		assert!(System::events().is_empty());
+
+		Timestamp::set_timestamp(9);
+		SimpleOracleModule::on_initialize(1);
+		assert_eq!(SimpleOracleModule::oracle_data(), None);
+
+		Timestamp::set_timestamp(10);
+		SimpleOracleModule::on_initialize(2);
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::DataReleased {
+			sequence: 0,
+			data: 0u64.to_be_bytes().to_vec(),
+			saved_at: 0,
+			confidence: None,
+		}));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![0u64.to_be_bytes().to_vec()]));
+
+		// Due data is released on push as well
+		push(15);
+		assert_eq!(
+			SimpleOracleModule::oracle_data(),
+			Some(vec![0u64.to_be_bytes().to_vec(), 5u64.to_be_bytes().to_vec()])
+		);
+		assert_eq!(
+			crate::EmbargoedData::<Test>::get(),
+			[crate::Embargoed {
+				release_at: 25,
+				saved_at: 15,
+				data: 15u64.to_be_bytes().to_vec(),
+				confidence: None,
+			}]
+		);
+	});
+}
+
+#[test]
+fn test_push_data_timelocked() {
+	new_test_ext().execute_with(|| {
+		let data_of_moment = |moment: u64| moment.to_be_bytes().to_vec();
+		assert_noop!(
+			SimpleOracleModule::push_data_timelocked(RuntimeOrigin::signed(1), DATA.to_vec(), 10),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(SimpleOracleModule::push_data_timelocked(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			data_of_moment(0),
+			10,
+		));
+		assert_eq!(SimpleOracleModule::last_seen(&Test::DEFAULT_ORACLE_ACCOUNT_ID), Some(0));
+		assert_eq!(SimpleOracleModule::oracle_data(), None);
+		// Data pushed later isn't held back by the timelocked one
+		push_at(5, &data_of_moment(5));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![data_of_moment(5)]));
+
+		Timestamp::set_timestamp(9);
+		SimpleOracleModule::on_initialize(1);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![data_of_moment(5)]));
+
+		// Stored as of its release moment
+		Timestamp::set_timestamp(10);
+		SimpleOracleModule::on_initialize(2);
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::DataReleased {
+			sequence: 1,
+			data: data_of_moment(0),
+			saved_at: 10,
+			confidence: None,
+		}));
+		assert_eq!(
+			SimpleOracleModule::oracle_data(),
+			Some(vec![data_of_moment(5), data_of_moment(0)])
+		);
+		assert!(crate::TimelockedData::<Test>::get().is_empty());
+
+		// Data can't be locked for longer than `MaxReleaseDelay`
+		let max_delay = <Test as crate::Config>::MaxReleaseDelay::get();
+		assert_noop!(
+			SimpleOracleModule::push_data_timelocked(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				data_of_moment(10),
+				10 + max_delay + 1,
+			),
+			Error::<Test>::ReleaseTooLate
+		);
+
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			data_of_moment(10),
+		));
+		assert_eq!(SimpleOracleModule::oracle_data().unwrap().len(), 3);
+
+		// Timelocked data is checked for duplicates like any push
+		OnDuplicate::set(DuplicatePolicy::Reject);
+		Timestamp::set_timestamp(11);
+		assert_noop!(
+			SimpleOracleModule::push_data_timelocked(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				data_of_moment(10),
+				20,
+			),
+			Error::<Test>::DuplicateData
+		);
+		assert_ok!(SimpleOracleModule::push_data_timelocked(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			data_of_moment(11),
+			20,
+		));
+		assert_eq!(SimpleOracleModule::last_seen(&Test::DEFAULT_ORACLE_ACCOUNT_ID), Some(11));
+	});
+}
+
+#[test]
+fn test_suppress_unchanged() {
+	new_test_ext().execute_with(|| {
+		SuppressUnchanged::set(true);
+		let push = |data: &[u8]| {
+			assert_ok!(SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				data.to_vec(),
+			));
+		};
+
+		push(b"0");
+		push(b"0");
+		push(b"1");
+		push(b"0");
+
+		assert_eq!(SimpleOracleModule::oracle_data().unwrap().len(), 4);
+		assert_eq!(
+			System::events().into_iter().map(|record| record.event).collect::<Vec<_>>(),
+			[(0, b"0"), (2, b"1"), (3, b"0")].map(|(sequence, data)| {
+				RuntimeEvent::SimpleOracleModule(Event::Emitted {
+					sequence,
+					data: data.to_vec(),
+					confidence: None,
+				})
+			})
+		);
+	});
+}
+
+#[test]
+fn test_emit_every() {
+	new_test_ext().execute_with(|| {
+		EmitEvery::set(3);
+		(0..7u8).for_each(|data| {
+			assert_ok!(SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				vec![data],
+			));
+		});
+
+		assert_eq!(SimpleOracleModule::oracle_data().unwrap().len(), 7);
+		assert_eq!(
+			System::events().into_iter().map(|record| record.event).collect::<Vec<_>>(),
+			[2, 5].map(|data| RuntimeEvent::SimpleOracleModule(Event::EmittedSummary {
+				pushes: 3,
+				sequence: data.into(),
+				data: vec![data],
+				confidence: None,
+			}))
+		);
+		assert_eq!(crate::UnreportedPushes::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn test_storage_only() {
+	new_test_ext().execute_with(|| {
+		StorageOnly::set(true);
+		EmitEvery::set(2);
+		(0..4u8).for_each(|data| {
+			assert_ok!(SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				vec![data],
+			));
+		});
+
+		VisibilityDelay::set(5);
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			vec![4],
+		));
+		Timestamp::set_timestamp(5);
+		SimpleOracleModule::on_initialize(2);
+
+		assert_eq!(SimpleOracleModule::oracle_data().unwrap().len(), 5);
+		assert_eq!(SimpleOracleModule::entry_by_sequence(4), Some((vec![4], 0)));
+		assert!(System::events().is_empty());
+		assert_eq!(crate::UnreportedPushes::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn test_entry_by_sequence() {
+	new_test_ext().execute_with(|| {
+		let lifetime = data_lifetime();
+		assert_eq!(SimpleOracleModule::last_sequence(), None);
+		assert_eq!(SimpleOracleModule::entry_by_sequence(0), None);
+
+		[0, 1, lifetime].into_iter().for_each(|moment: u64| {
+			Timestamp::set_timestamp(moment);
+			assert_ok!(SimpleOracleModule::push_data_fast(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				moment.to_be_bytes().to_vec(),
+			));
+		});
+		assert_eq!(SimpleOracleModule::last_sequence(), Some(2));
+
+		// Outdated
+		assert_eq!(SimpleOracleModule::entry_by_sequence(0), None);
+		assert_eq!(
+			SimpleOracleModule::entry_by_sequence(1),
+			Some((1u64.to_be_bytes().to_vec(), 1))
+		);
+		assert_eq!(
+			SimpleOracleModule::entry_by_sequence(2),
+			Some((lifetime.to_be_bytes().to_vec(), lifetime))
+		);
+		assert_eq!(SimpleOracleModule::entry_by_sequence(3), None);
+
+		// Numbers don't shift after cleanup
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+		assert_eq!(
+			SimpleOracleModule::entry_by_sequence(1),
+			Some((1u64.to_be_bytes().to_vec(), 1))
+		);
+		assert_eq!(SimpleOracleModule::last_sequence(), Some(2));
+	});
+}
+
+#[test]
+fn test_entry_by_hash() {
+	new_test_ext().execute_with(|| {
+		let lifetime = data_lifetime();
+		let hash = |data: &[u8]| BlakeTwo256::hash(data);
+
+		push_at(0, b"first");
+		push_at(10, b"second");
+		assert_eq!(
+			SimpleOracleModule::entry_by_hash(hash(b"first")),
+			Some((0, b"first".to_vec(), 0))
+		);
+		assert_eq!(
+			SimpleOracleModule::entry_by_hash(hash(b"second")),
+			Some((1, b"second".to_vec(), 10))
+		);
+		assert_eq!(SimpleOracleModule::entry_by_hash(hash(b"third")), None);
+
+		// The newest entry with the same payload is found
+		push_at(20, b"first");
+		assert_eq!(
+			SimpleOracleModule::entry_by_hash(hash(b"first")),
+			Some((2, b"first".to_vec(), 20))
+		);
+
+		// Pruned entries are removed from the index, unless the payload is pushed again
+		Timestamp::set_timestamp(lifetime + 10);
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+		assert_eq!(crate::HashIndex::<Test>::get(hash(b"second")), None);
+		assert_eq!(crate::HashIndex::<Test>::get(hash(b"first")), Some(2));
+
+		// Redacted entries aren't found by their original payload
+		assert_ok!(SimpleOracleModule::redact_entry(RuntimeOrigin::root(), 2));
+		assert_eq!(SimpleOracleModule::entry_by_hash(hash(b"first")), None);
+	});
+}
+
+#[test]
+fn test_on_duplicate() {
+	new_test_ext().execute_with(|| {
+		let lifetime = data_lifetime();
+		let push = |moment: u64| {
+			Timestamp::set_timestamp(moment);
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec(),
+			)
+		};
+
+		assert_ok!(push(0));
+		assert_ok!(push(10));
+		assert_eq!(SimpleOracleModule::stored_entries(), 2);
+
+		OnDuplicate::set(DuplicatePolicy::Reject);
+		assert_noop!(push(20), Error::<Test>::DuplicateData);
+
+		OnDuplicate::set(DuplicatePolicy::Flag);
+		assert_ok!(push(20));
+		System::assert_has_event(RuntimeEvent::SimpleOracleModule(Event::DuplicateSubmitted {
+			who: Test::DEFAULT_ORACLE_ACCOUNT_ID,
+			original: 1,
+		}));
+		assert_eq!(SimpleOracleModule::stored_entries(), 3);
+
+		// Only alive entries are duplicated
+		OnDuplicate::set(DuplicatePolicy::Reject);
+		assert_ok!(push(lifetime + 20));
+	});
+}
+
+#[test]
+fn test_last_pushed_id() {
+	new_test_ext().execute_with(|| {
+		EmitEvery::set(10);
+		assert_eq!(SimpleOracleModule::last_pushed_id(&Test::DEFAULT_ORACLE_ACCOUNT_ID), None);
+
+		for moment in 0..3 {
+			push_at(moment, &moment.to_be_bytes());
+		}
+
+		// No event is deposited for the pushes, but the id is still known
+		assert!(System::events().is_empty());
+		let id = SimpleOracleModule::last_pushed_id(&Test::DEFAULT_ORACLE_ACCOUNT_ID).unwrap();
+		assert_eq!(id, 2);
+		assert_eq!(
+			SimpleOracleModule::entry_by_sequence(id),
+			Some((2u64.to_be_bytes().to_vec(), 2))
+		);
+	});
+}
+
+#[test]
+fn test_announced_push() {
+	new_test_ext().execute_with(|| {
+		let hash = BlakeTwo256::hash(&DATA);
+		assert_noop!(
+			SimpleOracleModule::announce_push(RuntimeOrigin::signed(1), hash),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			SimpleOracleModule::execute_push(RuntimeOrigin::signed(1), DATA.to_vec()),
+			Error::<Test>::NotAnnounced
+		);
+
+		assert_ok!(SimpleOracleModule::announce_push(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			hash
+		));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::PushAnnounced { hash }));
+		assert_noop!(
+			SimpleOracleModule::execute_push(RuntimeOrigin::signed(1), b"other".to_vec()),
+			Error::<Test>::NotAnnounced
+		);
+
+		// Anyone can submit the announced data
+		assert_ok!(SimpleOracleModule::execute_push(RuntimeOrigin::signed(1), DATA.to_vec()));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+		assert_eq!(
+			System::events().last().unwrap().topics[1],
+			BlakeTwo256::hash_of(&Test::DEFAULT_ORACLE_ACCOUNT_ID)
+		);
+
+		// The announcement is consumed
+		assert_noop!(
+			SimpleOracleModule::execute_push(RuntimeOrigin::signed(1), DATA.to_vec()),
+			Error::<Test>::NotAnnounced
+		);
+	});
+}
+
+#[test]
+fn test_announced_push_authority_change() {
+	new_test_ext().execute_with(|| {
+		let hash = BlakeTwo256::hash(&DATA);
+		let announce = || {
+			assert_ok!(SimpleOracleModule::announce_push(
+				RuntimeOrigin::signed(SimpleOracleModule::authority()),
+				hash
+			));
+		};
+
+		// Dropped by an immediate change
+		announce();
+		assert_ok!(SimpleOracleModule::set_authority(RuntimeOrigin::root(), 5));
+		assert_eq!(crate::AnnouncedPush::<Test>::get(), None);
+		assert_noop!(
+			SimpleOracleModule::execute_push(RuntimeOrigin::signed(1), DATA.to_vec()),
+			Error::<Test>::NotAnnounced
+		);
+
+		// Kept until a scheduled change takes effect
+		AuthorityPeriod::set(10);
+		System::set_block_number(3);
+		announce();
+		assert_ok!(SimpleOracleModule::set_authority(RuntimeOrigin::root(), 6));
+		SimpleOracleModule::on_initialize(9);
+		assert_eq!(crate::AnnouncedPush::<Test>::get(), Some(hash));
+
+		SimpleOracleModule::on_initialize(10);
+		assert_eq!(SimpleOracleModule::authority(), 6);
+		assert_noop!(
+			SimpleOracleModule::execute_push(RuntimeOrigin::signed(1), DATA.to_vec()),
+			Error::<Test>::NotAnnounced
+		);
+	});
+}
+
+#[test]
+fn oracle_proxy() {
+	let push = crate::Call::<Test>::push_data { data: DATA.to_vec() };
+	let set_authority = crate::Call::<Test>::set_authority { authority: 1 };
+
+	assert!(OracleProxy::Any.filter(&push));
+	assert!(OracleProxy::Any.filter(&set_authority));
+	assert!(OracleProxy::Push.filter(&push));
+	assert!(!OracleProxy::Push.filter(&set_authority));
+	assert!(OracleProxy::Push.filter(&crate::Call::<Test>::push_data_as { data: DATA.to_vec() }));
+	assert!(OracleProxy::Push
+		.filter(&crate::Call::<Test>::announce_push { hash: BlakeTwo256::hash(DATA) }));
+	assert!(OracleProxy::Push.filter(&crate::Call::<Test>::execute_push { data: DATA.to_vec() }));
+
+	assert!(<OracleProxy as InstanceFilter<crate::Call<Test>>>::is_superset(
+		&OracleProxy::Any,
+		&OracleProxy::Push
+	));
+	assert!(!<OracleProxy as InstanceFilter<crate::Call<Test>>>::is_superset(
+		&OracleProxy::Push,
+		&OracleProxy::Any
+	));
+}
+
+#[test]
+fn test_resort_storage() {
+	new_test_ext().execute_with(|| {
+		// Sequence numbers out of chronological order
+		let lifetime = data_lifetime();
+		for (sequence, (moment, data)) in
+			[(2, b"2"), (1, b"1"), (lifetime + 2, b"3")].into_iter().enumerate()
+		{
+			crate::FeedEntries::<Test>::insert(
+				OracleFeed::get(),
+				sequence as u64,
+				OracleData::new(data.to_vec(), moment, None),
+			);
+		}
+		crate::NextSequence::<Test>::put(3);
+		crate::EntryCount::<Test>::put(3);
+		let hash = |data: &[u8]| BlakeTwo256::hash(data);
+		crate::HashIndex::<Test>::insert(hash(b"2"), 0);
+		crate::HashIndex::<Test>::insert(hash(b"1"), 1);
+		crate::Redacted::<Test>::insert(1, hash(b"redacted"));
+		crate::LastPushedId::<Test>::insert(Test::DEFAULT_ORACLE_ACCOUNT_ID, 0);
+		crate::LatestValue::<Test>::put((lifetime + 2, b"3".to_vec()));
+		Timestamp::set_timestamp(lifetime + 3);
+
+		assert_eq!(SimpleOracleModule::oracle_data(), None);
+		assert_eq!(SimpleOracleModule::entry_by_sequence(2), None);
+
+		// Detected by hooks once, while walking the oldest entries
+		PendingPrunes::<Test>::put(1);
+		SimpleOracleModule::on_idle(1, Weight::from_ref_time(u64::MAX));
+		SimpleOracleModule::on_idle(1, Weight::from_ref_time(u64::MAX));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::StorageCorrupted));
+		assert_eq!(System::events().len(), 1);
+		// The cache may not hold the newest entry
+		assert_eq!(crate::LatestValue::<Test>::get(), None);
+		assert_eq!(SimpleOracleModule::latest_value(), None);
+		assert_noop!(
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec()
+			),
+			Error::<Test>::StorageCorrupted
+		);
+
+		assert_noop!(SimpleOracleModule::resort_storage(RuntimeOrigin::signed(1)), BadOrigin);
+		assert_ok!(SimpleOracleModule::resort_storage(RuntimeOrigin::root()));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::StorageResorted {
+			entries: 3,
+		}));
+		assert!(!crate::Corrupted::<Test>::get());
+		assert_eq!(SimpleOracleModule::oldest_entry(), Some((b"1".to_vec(), 1)));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![b"3".to_vec()]));
+
+		// Indexes follow the moved entries
+		assert_eq!(SimpleOracleModule::entry_by_sequence(2), Some((b"3".to_vec(), lifetime + 2)));
+		assert_eq!(crate::HashIndex::<Test>::get(hash(b"1")), Some(0));
+		assert_eq!(crate::HashIndex::<Test>::get(hash(b"2")), Some(1));
+		assert_eq!(crate::HashIndex::<Test>::get(hash(b"3")), Some(2));
+		assert_eq!(crate::Redacted::<Test>::get(0), Some(hash(b"redacted")));
+		assert_eq!(crate::Redacted::<Test>::get(1), None);
+		assert_eq!(SimpleOracleModule::last_pushed_id(&Test::DEFAULT_ORACLE_ACCOUNT_ID), Some(1));
+		assert_eq!(SimpleOracleModule::mmr_leaves(), 3);
+		assert_eq!(SimpleOracleModule::latest_value(), Some((b"3".to_vec(), 1)));
+
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec()
+		));
+	});
+}
+
+#[test]
+fn test_storage_introspection() {
+	new_test_ext().execute_with(|| {
+		assert!(SimpleOracleModule::is_storage_empty());
+		assert_eq!(SimpleOracleModule::stored_entries(), 0);
+		assert_eq!(SimpleOracleModule::oldest_entry(), None);
+
+		push_at(1, b"1");
+		push_at(2, b"22");
+
+		assert!(!SimpleOracleModule::is_storage_empty());
+		assert_eq!(SimpleOracleModule::stored_entries(), 2);
+		assert_eq!(SimpleOracleModule::stored_bytes(), 3);
+		assert_eq!(SimpleOracleModule::oldest_entry(), Some((b"1".to_vec(), 1)));
+		assert_eq!(SimpleOracleModule::newest_entry(), Some((b"22".to_vec(), 2)));
+
+		// Outdated entries are still inspected
+		let lifetime = data_lifetime();
+		Timestamp::set_timestamp(lifetime + 10);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![]));
+		assert_eq!(SimpleOracleModule::stored_entries(), 2);
+	});
+}
+
+#[test]
+fn test_snapshot() {
+	new_test_ext().execute_with(|| {
+		for moment in 1..=4u8 {
+			push_at(moment.into(), &[moment; 32]);
+		}
+		let stored = SimpleOracleModule::oracle_data();
+
+		assert_noop!(SimpleOracleModule::export_snapshot(RuntimeOrigin::signed(1), 0), BadOrigin);
+		let mut chunks = Vec::new();
+		loop {
+			assert_ok!(SimpleOracleModule::export_snapshot(
+				RuntimeOrigin::root(),
+				chunks.len() as u32
+			));
+			match System::events().pop().map(|record| record.event) {
+				Some(RuntimeEvent::SimpleOracleModule(Event::SnapshotExported {
+					hash,
+					chunk,
+					chunks: total,
+					data,
+				})) => {
+					assert_eq!(chunk as usize, chunks.len());
+					chunks.push((hash, data));
+					if chunks.len() == total as usize {
+						break
+					}
+				},
+				event => panic!("unexpected event: {:?}", event),
+			}
+		}
+		assert!(chunks.len() > 1);
+		assert_noop!(
+			SimpleOracleModule::export_snapshot(RuntimeOrigin::root(), chunks.len() as u32),
+			Error::<Test>::InvalidSnapshot
+		);
+
+		let _ = crate::FeedEntries::<Test>::clear_prefix(OracleFeed::get(), u32::MAX, None);
+		crate::EntryCount::<Test>::kill();
+		crate::NextSequence::<Test>::kill();
+		assert_noop!(
+			SimpleOracleModule::import_snapshot(RuntimeOrigin::signed(1), chunks[0].0, vec![]),
+			BadOrigin
+		);
+		// Chunks of another snapshot are dropped
+		assert_ok!(SimpleOracleModule::import_snapshot(
+			RuntimeOrigin::root(),
+			BlakeTwo256::hash(b"other"),
+			chunks[0].1.clone()
+		));
+		for (hash, data) in chunks.iter().cloned() {
+			assert_eq!(SimpleOracleModule::oracle_data(), None);
+			assert_ok!(SimpleOracleModule::import_snapshot(RuntimeOrigin::root(), hash, data));
+		}
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::SnapshotImported {
+			hash: chunks[0].0,
+			entries: 4,
+		}));
+		assert_eq!(SimpleOracleModule::oracle_data(), stored);
+		assert!(crate::SnapshotImport::<Test>::get().is_none());
+
+		// Snapshots can't be bigger than the max stored data
+		let hash = BlakeTwo256::hash(b"huge");
+		assert_ok!(SimpleOracleModule::import_snapshot(RuntimeOrigin::root(), hash, vec![0; 1024]));
+		assert_noop!(
+			SimpleOracleModule::import_snapshot(RuntimeOrigin::root(), hash, vec![0; 4096 * 100]),
+			Error::<Test>::InvalidSnapshot
+		);
+		crate::SnapshotImport::<Test>::kill();
+
+		// Snapshot matching the hash must still be a valid storage
+		let invalid = vec![(b"2".to_vec(), 2u64, None::<u128>), (b"1".to_vec(), 1, None)].encode();
+		assert_noop!(
+			SimpleOracleModule::import_snapshot(
+				RuntimeOrigin::root(),
+				BlakeTwo256::hash(&invalid),
+				invalid
+			),
+			Error::<Test>::InvalidSnapshot
+		);
+	});
+}
+
+#[test]
+fn test_snapshot_reindex() {
+	new_test_ext().execute_with(|| {
+		let authority = Test::DEFAULT_ORACLE_ACCOUNT_ID;
+		for (moment, data) in [(1, b"a"), (2, b"b"), (3, b"c")] {
+			Timestamp::set_timestamp(moment);
+			assert_ok!(SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(authority),
+				data.to_vec()
+			));
+		}
+		assert_ok!(SimpleOracleModule::redact_entry(RuntimeOrigin::root(), 1));
+
+		let snapshot = vec![(b"x".to_vec(), 1u64, Some(5u128)), (b"y".to_vec(), 2, None)].encode();
+		assert_ok!(SimpleOracleModule::import_snapshot(
+			RuntimeOrigin::root(),
+			BlakeTwo256::hash(&snapshot),
+			snapshot
+		));
+
+		// Imported entries are numbered by their positions
+		assert_eq!(SimpleOracleModule::entry_by_hash(BlakeTwo256::hash(b"a")), None);
+		assert_eq!(
+			SimpleOracleModule::entry_by_hash(BlakeTwo256::hash(b"y")),
+			Some((1, b"y".to_vec(), 2))
+		);
+		assert_eq!(SimpleOracleModule::confidence_by_sequence(0), Some(5));
+		assert_eq!(SimpleOracleModule::last_pushed_id(&authority), None);
+		assert_eq!(crate::Redacted::<Test>::get(1), None);
+		assert_eq!(SimpleOracleModule::mmr_leaves(), 2);
+
+		assert_ok!(SimpleOracleModule::push_data_with_confidence(
+			RuntimeOrigin::signed(authority),
+			b"z".to_vec(),
+			7
+		));
+		assert_eq!(
+			SimpleOracleModule::entry_by_hash(BlakeTwo256::hash(b"z")),
+			Some((2, b"z".to_vec(), 3))
+		);
+		assert_eq!(SimpleOracleModule::confidence_by_sequence(2), Some(7));
+		assert_eq!(SimpleOracleModule::confidence_by_sequence(0), Some(5));
+		assert_eq!(SimpleOracleModule::last_pushed_id(&authority), Some(2));
+		assert_eq!(SimpleOracleModule::mmr_leaves(), 3);
+	});
+}
+
+#[test]
+fn test_redact_entry() {
+	new_test_ext().execute_with(|| {
+		for data in [b"0", b"1"] {
+			assert_ok!(SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				data.to_vec()
+			));
+		}
+
+		assert_noop!(SimpleOracleModule::redact_entry(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_noop!(
+			SimpleOracleModule::redact_entry(RuntimeOrigin::root(), 2),
+			Error::<Test>::EntryNotFound
+		);
+
+		assert_ok!(SimpleOracleModule::redact_entry(RuntimeOrigin::root(), 0));
+		let hash = BlakeTwo256::hash(b"0");
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::EntryRedacted {
+			sequence: 0,
+			hash,
+		}));
+		assert_eq!(crate::Redacted::<Test>::get(0), Some(hash));
+		assert_eq!(crate::HashIndex::<Test>::get(hash), None);
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![vec![], b"1".to_vec()]));
+		assert_eq!(
+			SimpleOracleModule::entry_by_sequence(1).map(|(data, _)| data),
+			Some(b"1".to_vec())
+		);
+	});
+}
+
+#[test]
+fn test_health() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			SimpleOracleModule::health(),
+			crate::OracleHealth {
+				entries: 0,
+				oldest: None,
+				newest: None,
+				authority: Test::DEFAULT_ORACLE_ACCOUNT_ID,
+				last_seen: None,
+				paused: false,
+				utilization: Permill::from_parts(0),
+			}
+		);
+
+		for moment in [1, 2] {
+			push_at(moment, &DATA);
+		}
+		// Rejected pushes aren't seen
+		Timestamp::set_timestamp(3);
+		assert_noop!(
+			SimpleOracleModule::push_data(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				vec![0; 65]
+			),
+			Error::<Test>::DataTooLarge
+		);
+		assert_ok!(SimpleOracleModule::set_paused(
+			RuntimeOrigin::signed(PauseAccount::get()),
+			true
+		));
+
+		assert_eq!(
+			SimpleOracleModule::health(),
+			crate::OracleHealth {
+				entries: 2,
+				oldest: Some(1),
+				newest: Some(2),
+				authority: Test::DEFAULT_ORACLE_ACCOUNT_ID,
+				last_seen: Some(2),
+				paused: true,
+				utilization: Permill::from_rational(2u32, 4096),
+			}
+		);
+	});
+}
+
+#[test]
+fn test_push_data_observed() {
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(10);
+		assert_noop!(
+			SimpleOracleModule::push_data_observed(RuntimeOrigin::signed(1), DATA.to_vec(), 7),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(SimpleOracleModule::push_data_observed(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+			7
+		));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::SubmissionLatency {
+			observed_at: 7,
+			accepted_at: 10,
+			latency: 3,
+		}));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+
+		// Observed in the future
+		assert_ok!(SimpleOracleModule::push_data_observed(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+			12
+		));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::SubmissionLatency {
+			observed_at: 12,
+			accepted_at: 10,
+			latency: 0,
+		}));
+	});
+}
+
+/// Push `data` at `moment` on behalf of the default authority
+fn push_at(moment: u64, data: &[u8]) {
+	Timestamp::set_timestamp(moment);
+	assert_ok!(SimpleOracleModule::push_data(
+		RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+		data.to_vec(),
+	));
+}
+
+/// Lifetime of oracle data in the mock, unless overridden
+fn data_lifetime() -> u64 {
+	<Test as crate::Config>::OracleDataLifetime::get()
+}
+
+/// VAA with `body` signed by `guardians` with their indices
+fn signed_vaa(guardian_set: u32, guardians: &[(u8, &ecdsa::Pair)], body: &[u8]) -> Vec<u8> {
+	let digest = wormhole::digest(body);
+	let mut vaa = vec![wormhole::VAA_VERSION];
+	vaa.extend(guardian_set.to_be_bytes());
+	vaa.push(guardians.len() as u8);
+	for (index, pair) in guardians {
+		vaa.push(*index);
+		vaa.extend(pair.sign_prehashed(&digest).0);
+	}
+	vaa.extend(body);
+	vaa
+}
+
+fn vaa_body(emitter: (u16, [u8; 32]), sequence: u64, payload: &[u8]) -> Vec<u8> {
+	let mut body = Vec::new();
+	body.extend(1u32.to_be_bytes());
+	body.extend(0u32.to_be_bytes());
+	body.extend(emitter.0.to_be_bytes());
+	body.extend(emitter.1);
+	body.extend(sequence.to_be_bytes());
+	body.push(1);
+	body.extend(payload);
+	body
+}
+
+#[test]
+fn test_submit_vaa() {
+	new_test_ext().execute_with(|| {
+		let pairs: Vec<_> = (1..=3u8).map(|seed| ecdsa::Pair::from_seed(&[seed; 32])).collect();
+		let addresses = pairs
+			.iter()
+			.map(|pair| {
+				let digest = [0; 32];
+				let public = sp_io::crypto::secp256k1_ecdsa_recover(
+					&pair.sign_prehashed(&digest).0,
+					&digest,
+				)
+				.unwrap();
+				wormhole::guardian_address(&public)
+			})
+			.collect();
+		WormholeGuardians::set(wormhole::GuardianSet { index: 4, addresses });
+		let submit = |vaa| SimpleOracleModule::submit_vaa(RuntimeOrigin::signed(1), vaa);
+
+		let body = vaa_body(VaaEmitter::get(), 7, &DATA);
+		// 2 of 3 guardians aren't a quorum
+		assert_noop!(
+			submit(signed_vaa(4, &[(0, &pairs[0]), (1, &pairs[1])], &body)),
+			Error::<Test>::InvalidVaa
+		);
+		assert_noop!(
+			submit(signed_vaa(3, &[(0, &pairs[0]), (1, &pairs[1]), (2, &pairs[2])], &body)),
+			Error::<Test>::InvalidVaa
+		);
+		// Signature attributed to another guardian
+		assert_noop!(
+			submit(signed_vaa(4, &[(0, &pairs[0]), (1, &pairs[2]), (2, &pairs[1])], &body)),
+			Error::<Test>::InvalidVaa
+		);
+		assert_noop!(
+			submit(signed_vaa(
+				4,
+				&[(0, &pairs[0]), (1, &pairs[1]), (2, &pairs[2])],
+				&vaa_body((2, [1; 32]), 7, &DATA)
+			)),
+			Error::<Test>::UnknownEmitter
+		);
+
+		let guardians = [(0, &pairs[0]), (1, &pairs[1]), (2, &pairs[2])];
+		assert_ok!(submit(signed_vaa(4, &guardians, &body)));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::Emitted {
+			sequence: 0,
+			data: DATA.to_vec(),
+			confidence: None,
+		}));
+		// Attributed to the authority, not the relayer
+		assert_eq!(SimpleOracleModule::last_seen(&1), None);
+		assert_eq!(SimpleOracleModule::last_pushed_id(&Test::DEFAULT_ORACLE_ACCOUNT_ID), Some(0));
+
+		// Replayed VAA
+		assert_noop!(submit(signed_vaa(4, &guardians, &body)), Error::<Test>::StaleSubmission);
+	});
+}
+
+#[test]
+fn test_relay_band_result() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SimpleOracleModule::relay_band_result(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				1,
+				4,
+				DATA.to_vec()
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_noop!(
+			SimpleOracleModule::relay_band_result(
+				RuntimeOrigin::signed(Test::BAND_RELAYER_ID),
+				5,
+				2,
+				DATA.to_vec()
+			),
+			Error::<Test>::NotEnoughAnswers
+		);
+
+		assert_ok!(SimpleOracleModule::relay_band_result(
+			RuntimeOrigin::signed(Test::BAND_RELAYER_ID),
+			5,
+			4,
+			DATA.to_vec()
+		));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::BandResultRelayed {
+			request_id: 5,
+			ans_count: 4,
+		}));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+		// Pushed on behalf of the authority, as VAAs are
+		assert_eq!(SimpleOracleModule::last_pushed_id(&Test::DEFAULT_ORACLE_ACCOUNT_ID), Some(0));
+		assert_eq!(SimpleOracleModule::last_pushed_id(&Test::BAND_RELAYER_ID), None);
+
+		assert_noop!(
+			SimpleOracleModule::relay_band_result(
+				RuntimeOrigin::signed(Test::BAND_RELAYER_ID),
+				5,
+				4,
+				DATA.to_vec()
+			),
+			Error::<Test>::StaleSubmission
+		);
+	});
+}
+
+#[test]
+fn test_push_data_with_confidence() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SimpleOracleModule::push_data_with_confidence(
+				RuntimeOrigin::signed(1),
+				150u128.encode(),
+				5
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			100u128.encode()
+		));
+		assert_ok!(SimpleOracleModule::push_data_with_confidence(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			150u128.encode(),
+			5
+		));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::Emitted {
+			sequence: 1,
+			data: 150u128.encode(),
+			confidence: Some(5),
+		}));
+		assert_eq!(SimpleOracleModule::confidence_by_sequence(0), None);
+		assert_eq!(SimpleOracleModule::confidence_by_sequence(1), Some(5));
+		assert_eq!(SimpleOracleModule::confidence_by_sequence(2), None);
+	});
+}
+
+#[test]
+fn test_migrate_to_v1() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<SimpleOracleModule>();
+		let entries = vec![(b"1".to_vec(), 1u64), (b"2".to_vec(), 2)];
+		frame_support::storage::unhashed::put_raw(
+			&crate::migrations::v1::EventsStorage::<Test>::hashed_key(),
+			&entries.encode(),
+		);
+		frame_support::storage::unhashed::put_raw(
+			&crate::EmbargoedData::<Test>::hashed_key(),
+			&vec![(5u64, 3u64, b"3".to_vec())].encode(),
+		);
+		Timestamp::set_timestamp(3);
+
+		#[cfg(feature = "try-runtime")]
+		let state = crate::migrations::v1::MigrateToV1::<Test>::pre_upgrade().unwrap();
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(crate::migrations::v1::MigrateToV1::<Test>::post_upgrade(state));
+		assert_eq!(SimpleOracleModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			crate::migrations::v1::EventsStorage::<Test>::get().map(|storage| storage.len()),
+			Some(2)
+		);
+		assert_eq!(
+			crate::EmbargoedData::<Test>::get(),
+			[crate::Embargoed {
+				release_at: 5,
+				saved_at: 3,
+				data: b"3".to_vec(),
+				confidence: None
+			}]
+		);
+
+		// Undecodable data is left in place & migrated by a later upgrade
+		StorageVersion::new(0).put::<SimpleOracleModule>();
+		let key = crate::migrations::v1::EventsStorage::<Test>::hashed_key();
+		frame_support::storage::unhashed::put_raw(&key, &[0xff]);
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(frame_support::storage::unhashed::get_raw(&key), Some(vec![0xff]));
+		assert_eq!(SimpleOracleModule::on_chain_storage_version(), 0);
+		assert_eq!(crate::EmbargoedData::<Test>::get().len(), 1);
+	});
+}
+
+#[test]
+fn test_migrate_to_v2() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<SimpleOracleModule>();
+		let entries: Vec<(Vec<u8>, u64, Option<u128>)> =
+			vec![(b"1".to_vec(), 1, None), (b"2".to_vec(), 1, None), (b"3".to_vec(), 2, None)];
+		frame_support::storage::unhashed::put_raw(
+			&crate::migrations::v1::EventsStorage::<Test>::hashed_key(),
+			&entries.encode(),
+		);
+		Timestamp::set_timestamp(3);
+
+		#[cfg(feature = "try-runtime")]
+		let state = crate::migrations::v2::MigrateToV2::<Test>::pre_upgrade().unwrap();
+		crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(crate::migrations::v2::MigrateToV2::<Test>::post_upgrade(state));
+		assert_eq!(SimpleOracleModule::on_chain_storage_version(), 2);
+		assert!(crate::migrations::v1::EventsStorage::<Test>::get().is_none());
+		assert_eq!(
+			SimpleOracleModule::oracle_data(),
+			Some(vec![b"1".to_vec(), b"2".to_vec(), b"3".to_vec()])
+		);
+		// Each entry has its own key
+		assert_eq!(
+			crate::FeedEntries::<Test>::iter_prefix(OracleFeed::get())
+				.map(|(sequence, _)| sequence)
+				.collect::<std::collections::BTreeSet<_>>(),
+			std::collections::BTreeSet::from([0, 1, 2])
+		);
+		assert_eq!(crate::EntryCount::<Test>::get(), 3);
+		assert_eq!(crate::NextSequence::<Test>::get(), Some(3));
+
+		// Applied once
+		let weight = crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(weight, <Test as frame_system::Config>::DbWeight::get().reads(1));
+
+		// Undecodable data is left in place
+		StorageVersion::new(1).put::<SimpleOracleModule>();
+		let key = crate::migrations::v1::EventsStorage::<Test>::hashed_key();
+		frame_support::storage::unhashed::put_raw(&key, &[0xff]);
+		crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(frame_support::storage::unhashed::get_raw(&key), Some(vec![0xff]));
+		assert_eq!(
+			SimpleOracleModule::oracle_data(),
+			Some(vec![b"1".to_vec(), b"2".to_vec(), b"3".to_vec()])
+		);
+	});
+}
+
+#[test]
+fn test_delete_feed_data() {
+	new_test_ext().execute_with(|| {
+		for moment in 1..=3u64 {
+			push_at(moment, &moment.to_be_bytes());
+		}
+		// Each entry has its own key
+		assert_eq!(crate::FeedEntries::<Test>::iter_prefix(OracleFeed::get()).count(), 3);
+		assert_ok!(SimpleOracleModule::redact_entry(RuntimeOrigin::root(), 0));
+		let queued =
+			crate::Embargoed { release_at: 10, saved_at: 4, data: DATA.to_vec(), confidence: None };
+		crate::EmbargoedData::<Test>::put(vec![queued.clone()]);
+		crate::TimelockedData::<Test>::put(vec![queued]);
+		crate::PendingPrunes::<Test>::put(1);
+		crate::UnreportedPushes::<Test>::put(3);
+
+		assert_noop!(SimpleOracleModule::delete_feed_data(RuntimeOrigin::signed(1)), BadOrigin);
+		assert_ok!(SimpleOracleModule::delete_feed_data(RuntimeOrigin::root()));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::FeedDataDeleted {
+			entries: 3,
+		}));
+		assert!(SimpleOracleModule::is_storage_empty());
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![]));
+		assert!(crate::LatestValue::<Test>::get().is_none());
+
+		// Indexes, queues & pending work of the deleted entries are reset
+		assert_eq!(crate::HashIndex::<Test>::iter().count(), 0);
+		assert_eq!(crate::Redacted::<Test>::get(0), None);
+		assert_eq!(SimpleOracleModule::last_pushed_id(&Test::DEFAULT_ORACLE_ACCOUNT_ID), None);
+		assert!(crate::EmbargoedData::<Test>::get().is_empty());
+		assert!(crate::TimelockedData::<Test>::get().is_empty());
+		assert_eq!(crate::PendingPrunes::<Test>::get(), 0);
+		assert_eq!(crate::UnreportedPushes::<Test>::get(), 0);
+
+		// Sequence numbers continue
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+		));
+		System::assert_last_event(RuntimeEvent::SimpleOracleModule(Event::Emitted {
+			sequence: 3,
+			data: DATA.to_vec(),
+			confidence: None,
+		}));
+	});
+}
+
+#[test]
+fn test_push_entry() {
+	new_test_ext().execute_with(|| {
+		let ticker = Ticker { price: 150, volume: 1_000, sources: 3 };
+		assert_noop!(
+			SimpleOracleModule::push_entry(RuntimeOrigin::signed(1), ticker.clone()),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(SimpleOracleModule::push_entry(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			ticker.clone()
+		));
+		// Not an entry
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec()
+		));
+
+		// Entry of the first schema
+		assert_ok!(SimpleOracleModule::push_data(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			(0u8, 200u128).encode()
+		));
+		let old_ticker = Ticker { price: 200, volume: 0, sources: 0 };
+
+		assert_eq!(
+			SimpleOracleModule::oracle_entries(),
+			Some(vec![ticker.clone(), old_ticker.clone()])
+		);
+		assert_eq!(
+			SimpleOracleModule::oracle_entries_page(None),
+			Some(crate::oracle_data::Page {
+				data: vec![ticker.clone(), old_ticker.clone()],
+				next: None
+			})
+		);
+		assert_eq!(
+			SimpleOracleModule::oracle_data_decoded(),
+			Some(vec![
+				((1u8, &ticker).encode(), Some(ticker)),
+				(DATA.to_vec(), None),
+				((0u8, 200u128).encode(), Some(old_ticker)),
+			])
+		);
+	});
+}
+
+#[test]
+fn test_feed_metadata() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			SimpleOracleModule::feed_metadata(),
+			crate::FeedMetadata {
+				feed: FeedId::pair(b"BTC", b"USD"),
+				decimals: 2,
+				entry_version: 1,
+			}
+		);
+	});
+}
+
+#[test]
+fn test_mmr() {
+	new_test_ext().execute_with(|| {
+		let merge = SimpleOracleModule::mmr_merge;
+		assert_eq!(SimpleOracleModule::mmr_root(), None);
+
+		push_at(0, b"first");
+		push_at(10, b"second");
+		push_at(20, b"third");
+		assert_eq!(SimpleOracleModule::mmr_leaves(), 3);
+
+		let leaves = [
+			SimpleOracleModule::mmr_leaf(0, b"first", 0),
+			SimpleOracleModule::mmr_leaf(1, b"second", 10),
+			SimpleOracleModule::mmr_leaf(2, b"third", 20),
+		];
+		let root = SimpleOracleModule::mmr_root().unwrap();
+		assert_eq!(root, merge(&merge(&leaves[0], &leaves[1]), &leaves[2]));
+
+		let proof = mmr::Proof {
+			leaf_index: 1,
+			leaf_count: 3,
+			siblings: vec![leaves[0]],
+			peaks: vec![leaves[2]],
+		};
+		assert!(mmr::verify(&root, leaves[1], &proof, merge));
+		assert!(!mmr::verify(&root, leaves[0], &proof, merge));
+
+		// Pruned entries stay provable
+		Timestamp::set_timestamp(data_lifetime() + 10);
+		assert_ok!(SimpleOracleModule::clean_outdated_data(RuntimeOrigin::none(), u32::MAX));
+		assert_eq!(SimpleOracleModule::mmr_root(), Some(root));
+	});
+}
+
+#[test]
+fn test_latest_value_keys() {
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(10);
+		assert_ok!(SimpleOracleModule::push_data_fast(
+			RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+			DATA.to_vec(),
+		));
+
+		let keys = SimpleOracleModule::latest_value_keys();
+		assert_eq!(
+			sp_io::storage::get(&keys[0]).map(|value| value.to_vec()),
+			Some((10u64, DATA.to_vec()).encode())
+		);
+		assert_eq!(sp_io::storage::get(&keys[1]).map(|value| value.to_vec()), Some(10u64.encode()));
+	});
+}
+
+#[test]
+fn test_push_hooks() {
+	new_test_ext().execute_with(|| {
+		let origin = || RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID);
+		Timestamp::set_timestamp(10);
+
+		assert_noop!(
+			SimpleOracleModule::push_data(origin(), TestPushFilter::VETOED.to_vec()),
+			Error::<Test>::PushFiltered
+		);
+		assert_ok!(SimpleOracleModule::push_data(origin(), TestPushFilter::RAW.to_vec()));
+		assert_eq!(
+			SimpleOracleModule::entry_by_sequence(0),
+			Some((TestPushFilter::FILTERED.to_vec(), 10))
+		);
+
+		// Embargoed data is observed once accepted
+		assert_ok!(SimpleOracleModule::push_data_timelocked(origin(), DATA.to_vec(), 20));
+		assert_eq!(
+			ObservedPushes::get(),
+			[
+				(Test::DEFAULT_ORACLE_ACCOUNT_ID, TestPushFilter::FILTERED.to_vec(), 10),
+				(Test::DEFAULT_ORACLE_ACCOUNT_ID, DATA.to_vec(), 10),
+			]
+		);
+	});
+}
+
+#[test]
+fn test_push_data_as() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SimpleOracleModule::push_data_as(
+				RuntimeOrigin::signed(Test::DEFAULT_ORACLE_ACCOUNT_ID),
+				DATA.to_vec()
+			),
+			BadOrigin
+		);
+
+		assert_ok!(SimpleOracleModule::set_paused(
+			RuntimeOrigin::signed(Test::PAUSE_ACCOUNT_ID),
+			true
+		));
+		assert_ok!(SimpleOracleModule::push_data_as(RuntimeOrigin::root(), DATA.to_vec()));
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+		assert_eq!(SimpleOracleModule::last_pushed_id(&Test::DEFAULT_ORACLE_ACCOUNT_ID), Some(0));
+	});
+}
+
+#[test]
+fn test_would_accept() {
+	new_test_ext().execute_with(|| {
+		let authority = Test::DEFAULT_ORACLE_ACCOUNT_ID;
+		let feed = OracleFeed::get();
+		Timestamp::set_timestamp(10);
+		assert_ok!(SimpleOracleModule::push_data(RuntimeOrigin::signed(authority), DATA.to_vec()));
+
+		assert_eq!(SimpleOracleModule::would_accept(authority, feed, DATA.to_vec(), 20), Ok(()));
+		assert_eq!(
+			SimpleOracleModule::would_accept(
+				authority,
+				FeedId::pair(b"ETH", b"USD"),
+				DATA.to_vec(),
+				20
+			),
+			Err(Error::<Test>::UnknownFeed.into())
+		);
+		assert_eq!(
+			SimpleOracleModule::would_accept(1, feed, DATA.to_vec(), 20),
+			Err(Error::<Test>::NotAuthorized.into())
+		);
+		assert_eq!(
+			SimpleOracleModule::would_accept(authority, feed, vec![0; 65], 20),
+			Err(Error::<Test>::DataTooLarge.into())
+		);
+		assert_eq!(
+			SimpleOracleModule::would_accept(authority, feed, DATA.to_vec(), 5),
+			Err(Error::<Test>::StaleSubmission.into())
+		);
+
+		// Nothing is written
+		assert_eq!(SimpleOracleModule::oracle_data(), Some(vec![DATA.to_vec()]));
+		assert_eq!(Timestamp::get(), 10);
+	});
+}
+
+#[test]
+fn test_test_utils() {
+	frame_support::parameter_types! {
+		pub const EurUsd: FeedId = FeedId::pair(b"EUR", b"USD");
+	}
+	type EurUsdProvider = test_utils::MockPriceProvider<EurUsd>;
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(<EurUsdProvider as PriceProvider<u64>>::latest_price(), None);
+		EurUsdProvider::set_price(Price(FixedU128::from_u32(2)), 5u64);
+		assert_eq!(<EurUsdProvider as PriceProvider<u64>>::feed_id(), EurUsd::get());
+		assert_eq!(
+			<EurUsdProvider as PriceProvider<u64>>::latest_price(),
+			Some((Price(FixedU128::from_u32(2)), 5))
+		);
+
+		// 20_000.00 USD per BTC with 2 decimals
+		test_utils::seed_prices::<Test>([(0, 1_000_000), (10, 2_000_000)]);
+		Timestamp::set_timestamp(10);
+		assert_eq!(
+			cross_rate::<u64, SimpleOracleModule, EurUsdProvider>(10),
+			Some((FeedId::pair(b"BTC", b"EUR"), Price(FixedU128::from_u32(10_000))))
+		);
+
+		EurUsdProvider::clear();
+		assert_eq!(cross_rate::<u64, SimpleOracleModule, EurUsdProvider>(10), None);
+	});
+}
+
+#[test]
+fn test_latest_value() {
+	new_test_ext().execute_with(|| {
+		let lifetime = data_lifetime();
+		assert_eq!(SimpleOracleModule::latest_value(), None);
+
+		push_at(0, b"first");
+		push_at(10, b"second");
+		assert_eq!(crate::LatestValue::<Test>::get(), Some((10, b"second".to_vec())));
+		Timestamp::set_timestamp(15);
+		assert_eq!(SimpleOracleModule::latest_value(), Some((b"second".to_vec(), 5)));
+		assert_eq!(SimpleOracleModule::latest_value(), SimpleOracleModule::value_at_or_before(15));
+
+		// Redactions & cleanups are reflected
+		assert_ok!(SimpleOracleModule::redact_entry(RuntimeOrigin::root(), 1));
+		assert_eq!(SimpleOracleModule::latest_value(), Some((vec![], 5)));
+		assert_ok!(SimpleOracleModule::force_clean(RuntimeOrigin::root(), 20));
+		assert_eq!(crate::LatestValue::<Test>::get(), None);
+		assert_eq!(SimpleOracleModule::latest_value(), None);
+
+		// Outdated data isn't returned
+		push_at(20, b"third");
+		Timestamp::set_timestamp(20 + lifetime);
+		assert_eq!(SimpleOracleModule::latest_value(), None);
+
+		// Missing cache falls back to the storage
+		Timestamp::set_timestamp(30);
+		crate::LatestValue::<Test>::kill();
+		assert_eq!(SimpleOracleModule::latest_value(), Some((b"third".to_vec(), 10)));
+
+		// Cached data saved after now isn't alive
+		push_at(30, b"fourth");
+		Timestamp::set_timestamp(25);
+		assert_eq!(SimpleOracleModule::latest_value(), None);
+
+		// Nothing is read from out-of-order storage
+		Timestamp::set_timestamp(35);
+		crate::FeedEntries::<Test>::insert(
+			OracleFeed::get(),
+			3,
+			OracleData::new(b"fourth".to_vec(), 10, None),
+		);
+		crate::LatestValue::<Test>::kill();
+		assert_eq!(SimpleOracleModule::latest_value(), None);
+	});
+}
@@ -0,0 +1,278 @@
+//! Weights for pallet_simple_oracle
+//!
+//! Weights cover execution only, calls add storage accesses by `T::DbWeight` on top,
+//! since those depend on the stored data rather than on the benchmarked worst case.
+//!
+//! The values are estimates until regenerated with `scripts/frame-weight-template.hbs`,
+//! see the Benchmarks section of the README.
+
+use frame_support::weights::Weight;
+use sp_std::marker::PhantomData;
+
+/// Information about pallets pub methods weight
+pub trait WeightInfo {
+	/// Weight of a push of `data_len` bytes, with `n_entries` entries stored
+	/// or waiting for their release
+	fn push_data(n_entries: u32, data_len: u32) -> Weight;
+	/// Weight of a push without cleanup, see [`WeightInfo::push_data`]
+	fn push_data_fast(n_entries: u32, data_len: u32) -> Weight;
+	/// Weight of cleaning `n_removed` outdated entries, archiving them included
+	fn clean_outdated_data(n_removed: u32) -> Weight;
+	fn set_lifetime() -> Weight;
+	fn set_retention_override() -> Weight;
+	fn set_authority() -> Weight;
+	fn set_paused() -> Weight;
+	/// Weight of force cleaning `n_removed` entries
+	fn force_clean(n_removed: u32) -> Weight;
+	/// Weight of releasing `n_released` embargoed or timelocked entries
+	fn release_data(n_released: u32) -> Weight;
+	/// Weight of a timelocked push, see [`WeightInfo::push_data`]
+	fn push_data_timelocked(n_entries: u32, data_len: u32) -> Weight;
+	fn announce_push() -> Weight;
+	/// Weight of reading `n_entries` entries of `data_len` bytes in total
+	/// out of storage holding `n_stored` entries
+	fn read_data(n_stored: u32, n_entries: u32, data_len: u32) -> Weight;
+	/// Weight of sorting storage holding `n_entries` entries
+	fn resort_storage(n_entries: u32) -> Weight;
+	fn transfer_feed_ownership() -> Weight;
+	fn accept_feed_ownership() -> Weight;
+	/// Weight of exporting a chunk of storage holding `n_entries` entries
+	fn export_snapshot(n_entries: u32) -> Weight;
+	/// Weight of importing a `chunk_len` bytes chunk of a snapshot of `n_entries` entries
+	fn import_snapshot(n_entries: u32, chunk_len: u32) -> Weight;
+	fn redact_entry() -> Weight;
+	/// Weight of a relayed push, see [`WeightInfo::push_data`]
+	fn relay_band_result(n_entries: u32, data_len: u32) -> Weight;
+	/// Weight of verifying a VAA with `n_signatures` signatures,
+	/// without storing the payload
+	fn submit_vaa(n_signatures: u32) -> Weight;
+	/// Weight of pruning `n_removed` moments of the archive
+	fn prune_archive(n_removed: u32) -> Weight;
+	/// Weight of deleting `n_removed` entries of the feed data
+	fn delete_feed_data(n_removed: u32) -> Weight;
+}
+
+/// Weights for pallet_simple_oracle on the reference hardware
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn push_data(n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn push_data_fast(n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(5_000)
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn clean_outdated_data(n_removed: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_removed.into()))
+	}
+
+	fn set_lifetime() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn set_retention_override() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn set_authority() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn set_paused() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn force_clean(n_removed: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_removed.into()))
+	}
+
+	fn release_data(n_released: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_released.into()))
+	}
+
+	fn push_data_timelocked(n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(5_000)
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn announce_push() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn read_data(n_stored: u32, n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(5_000)
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_stored.into()))
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn resort_storage(n_entries: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_entries.into()))
+	}
+
+	fn transfer_feed_ownership() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn accept_feed_ownership() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn export_snapshot(n_entries: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_entries.into()))
+	}
+
+	fn import_snapshot(n_entries: u32, chunk_len: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(chunk_len.into()))
+	}
+
+	fn redact_entry() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn relay_band_result(n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(20_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn submit_vaa(n_signatures: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(100_000).saturating_mul(n_signatures.into()))
+	}
+
+	fn prune_archive(n_removed: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_removed.into()))
+	}
+
+	fn delete_feed_data(n_removed: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_removed.into()))
+	}
+}
+
+/// For tests, same as [`SubstrateWeight`]
+impl WeightInfo for () {
+	fn push_data(n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn push_data_fast(n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(5_000)
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn clean_outdated_data(n_removed: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_removed.into()))
+	}
+
+	fn set_lifetime() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn set_retention_override() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn set_authority() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn set_paused() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn force_clean(n_removed: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_removed.into()))
+	}
+
+	fn release_data(n_released: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_released.into()))
+	}
+
+	fn push_data_timelocked(n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(5_000)
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn announce_push() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn read_data(n_stored: u32, n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(5_000)
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_stored.into()))
+			.saturating_add(Weight::from_ref_time(100).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn resort_storage(n_entries: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_entries.into()))
+	}
+
+	fn transfer_feed_ownership() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn accept_feed_ownership() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn export_snapshot(n_entries: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_entries.into()))
+	}
+
+	fn import_snapshot(n_entries: u32, chunk_len: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(chunk_len.into()))
+	}
+
+	fn redact_entry() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn relay_band_result(n_entries: u32, data_len: u32) -> Weight {
+		Weight::from_ref_time(20_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_entries.into()))
+			.saturating_add(Weight::from_ref_time(10).saturating_mul(data_len.into()))
+	}
+
+	fn submit_vaa(n_signatures: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(100_000).saturating_mul(n_signatures.into()))
+	}
+
+	fn prune_archive(n_removed: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_removed.into()))
+	}
+
+	fn delete_feed_data(n_removed: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+			.saturating_add(Weight::from_ref_time(1_000).saturating_mul(n_removed.into()))
+	}
+}
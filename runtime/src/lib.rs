@@ -29,7 +29,8 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
+		StorageInfo,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -67,6 +68,9 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// A timestamp: milliseconds since the unix epoch.
+pub type Moment = u64;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -106,7 +110,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 100,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -231,8 +235,7 @@ impl pallet_grandpa::Config for Runtime {
 }
 
 impl pallet_timestamp::Config for Runtime {
-	/// A timestamp: milliseconds since the unix epoch.
-	type Moment = u64;
+	type Moment = Moment;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
 	type WeightInfo = ();
@@ -287,12 +290,85 @@ impl Get<AccountId> for DefaultOracleAuthority {
 	}
 }
 
+parameter_types! {
+	pub const OracleFeed: pallet_simple_oracle::feed::FeedId =
+		pallet_simple_oracle::feed::FeedId::pair(b"DOT", b"USD");
+	// No guardians, so no VAA is accepted until the current guardian set is configured
+	pub OracleWormholeGuardians: pallet_simple_oracle::wormhole::GuardianSet =
+		Default::default();
+	// Identical readings are legitimate for a price feed
+	pub const OracleOnDuplicate: pallet_simple_oracle::DuplicatePolicy =
+		pallet_simple_oracle::DuplicatePolicy::Accept;
+	// Pyth price feeds emitter on Pythnet
+	pub const OracleVaaEmitter: (u16, [u8; 32]) = (26, [225, 1, 250, 237, 172, 88, 81, 227, 43, 155, 35, 181, 249, 65, 26, 140, 43, 172, 74, 174, 62, 212, 221, 123, 129, 29, 209, 167, 46, 164, 170, 113]);
+}
+
 /// Configure the pallet-simple-oracle in pallets/simple-oracle.
 impl pallet_simple_oracle::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type DefaultOracleAuthority = DefaultOracleAuthority;
+	type AuthorityPeriod = ConstU32<{ 10 * MINUTES }>;
+	type Feed = OracleFeed;
 	type OracleDataLifetime = ConstU64<3_600_000>;
-	type WeightInfo = ();
+	type PruneDelay = ConstU64<3_600_000>;
+	type ArchiveWindow = ConstU64<86_400_000>;
+	type ArchivePruneInterval = ConstU32<{ 10 * MINUTES }>;
+	type MaxArchiveDepth = ConstU32<100_000>;
+	type MaxArchiveBytes = ConstU32<{ 64 * 1024 * 1024 }>;
+	type VisibilityDelay = ConstU64<0>;
+	type MaxReleaseDelay = ConstU64<86_400_000>;
+	type OnDuplicate = OracleOnDuplicate;
+	type SuppressUnchanged = ConstBool<false>;
+	type EmitEvery = ConstU32<0>;
+	type StorageOnly = ConstBool<false>;
+	type Decimals = ConstU8<8>;
+	// Prices in units with `Decimals` decimal places
+	type Entry = u128;
+	type EntryVersion = ConstU8<0>;
+	type EntryDecoder = pallet_simple_oracle::decoder::SingleVersion<ConstU8<0>>;
+	type PrePushFilter = ();
+	type PostPushHook = ();
+	type MaxQueryEntries = ConstU32<1_000>;
+	type MaxQueryBytes = ConstU32<{ 1024 * 1024 }>;
+	type MaxPendingPrunes = ConstU32<100>;
+	type MaxDataLen = ConstU32<1_024>;
+	type MaxEntries = ConstU32<10_000>;
+	type MaxRejectedPushes = ConstU32<32>;
+	type SnapshotChunkLen = ConstU32<{ 64 * 1024 }>;
+	type WormholeGuardians = OracleWormholeGuardians;
+	type VaaEmitter = OracleVaaEmitter;
+	// No relayers until BandChain relaying is set up
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type BandRelayers = frame_support::traits::Nothing;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BandRelayers = frame_support::traits::Everything;
+	type MinBandAnswers = ConstU64<4>;
+	type ReportDecoder = pallet_simple_oracle::decoder::PythReport;
+	type CleanOrigin = pallet_simple_oracle::EnsureAnyone<AccountId>;
+	type SetLifetimeOrigin = frame_system::EnsureRoot<AccountId>;
+	type SetAuthorityOrigin = frame_system::EnsureRoot<AccountId>;
+	type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+	type RedactOrigin = frame_system::EnsureRoot<AccountId>;
+	type ManualPushOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = pallet_simple_oracle::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = OracleBenchmarkHelper;
+}
+
+/// Relays Pyth price messages in benchmarks of pallet-simple-oracle
+#[cfg(feature = "runtime-benchmarks")]
+pub struct OracleBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_simple_oracle::traits::BenchmarkHelper<AccountId> for OracleBenchmarkHelper {
+	fn band_relayer() -> AccountId {
+		AccountId::new([0; 32])
+	}
+
+	// Decoded payloads of Pyth messages are of a fixed size
+	fn report(_data_len: u32) -> Vec<u8> {
+		[&[0][..], &[0; 32], &1i64.to_be_bytes(), &0u64.to_be_bytes(), &0i32.to_be_bytes()].concat()
+	}
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -331,6 +407,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_simple_oracle::RecordRejectedPushes<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -345,8 +422,15 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations run on the runtime upgrade
+pub type Migrations = (
+	pallet_simple_oracle::migrations::v1::MigrateToV1<Runtime>,
+	pallet_simple_oracle::migrations::v2::MigrateToV2<Runtime>,
+);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -510,6 +594,97 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_simple_oracle_runtime_api::SimpleOracleApi<Block, Moment, AccountId, u128> for Runtime {
+		fn oracle_data(
+			cursor: Option<pallet_simple_oracle_runtime_api::Cursor<Moment>>,
+		) -> Option<pallet_simple_oracle_runtime_api::Page<Moment>> {
+			SimpleOracleModule::oracle_data_page(cursor)
+		}
+
+		fn read_cost(from: Moment, to: Moment) -> pallet_simple_oracle_runtime_api::ReadCost {
+			SimpleOracleModule::read_cost(from, to)
+		}
+
+		fn health() -> pallet_simple_oracle_runtime_api::OracleHealth<AccountId, Moment> {
+			SimpleOracleModule::health()
+		}
+
+		fn feed_metadata() -> pallet_simple_oracle_runtime_api::FeedMetadata {
+			SimpleOracleModule::feed_metadata()
+		}
+
+		fn oracle_entries(
+			cursor: Option<pallet_simple_oracle_runtime_api::Cursor<Moment>>,
+		) -> Option<pallet_simple_oracle_runtime_api::Page<Moment, u128>> {
+			SimpleOracleModule::oracle_entries_page(cursor)
+		}
+
+		fn archived_range(
+			from: Moment,
+			to: Moment,
+			cursor: Option<pallet_simple_oracle_runtime_api::Cursor<Moment>>,
+		) -> pallet_simple_oracle_runtime_api::Page<Moment> {
+			SimpleOracleModule::archived_range(from, to, cursor)
+		}
+
+		fn mmr_root() -> Option<Hash> {
+			SimpleOracleModule::mmr_root()
+		}
+
+		fn latest_value_keys() -> Vec<Vec<u8>> {
+			SimpleOracleModule::latest_value_keys()
+		}
+
+		fn would_accept(
+			who: AccountId,
+			feed: pallet_simple_oracle_runtime_api::FeedId,
+			data: Vec<u8>,
+			moment: Moment,
+		) -> Result<(), pallet_simple_oracle_runtime_api::RejectReason> {
+			SimpleOracleModule::would_accept(who, feed, data, moment)
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl frame_benchmarking::Benchmark<Block> for Runtime {
+		fn benchmark_metadata(extra: bool) -> (
+			Vec<frame_benchmarking::BenchmarkList>,
+			Vec<frame_support::traits::StorageInfo>,
+		) {
+			use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
+			use frame_support::traits::StorageInfoTrait;
+			use frame_system_benchmarking::Pallet as SystemBench;
+			use baseline::Pallet as BaselineBench;
+
+			let mut list = Vec::<BenchmarkList>::new();
+			list_benchmarks!(list, extra);
+
+			let storage_info = AllPalletsWithSystem::storage_info();
+
+			(list, storage_info)
+		}
+
+		fn dispatch_benchmark(
+			config: frame_benchmarking::BenchmarkConfig
+		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+			use frame_benchmarking::{baseline, Benchmarking, BenchmarkBatch, TrackedStorageKey};
+			use frame_support::traits::WhitelistedStorageKeys;
+			use frame_system_benchmarking::Pallet as SystemBench;
+			use baseline::Pallet as BaselineBench;
+
+			impl frame_system_benchmarking::Config for Runtime {}
+			impl baseline::Config for Runtime {}
+
+			let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
+
+			let mut batches = Vec::<BenchmarkBatch>::new();
+			let params = (&config, &whitelist);
+			add_benchmarks!(params, batches);
+
+			Ok(batches)
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade() -> (Weight, Weight) {